@@ -1,156 +1,266 @@
-//! A module that contains the [ImageRGB8] struct and related functions.
+//! A module that contains the generic [Image] struct, type aliases for all supported pixel formats and related functions.
 
-use std::path::Path;
 use std::fs::File;
 use std::io::BufWriter;
-use bytemuck::try_cast_slice;
-use std::cmp::{min, max};
-use std::f64::consts::FRAC_1_SQRT_2;
+use bytemuck::{cast_slice, cast_slice_mut};
+use crate::paint::Paint;
+use crate::path::{has_gaps, Path};
+use crate::pixel::{BlendMode, Channel, ColorType, Pixel};
+use crate::raster;
+use crate::style::{ArrowHead, FillRule, LineCap, LineJoin};
+
+
+fn bytes_to_pixels<P: Pixel>(bytes: &[u8]) -> Vec<P> {
+    // converts a slice of bytes to a vector of pixels (bytes are copied, so they don't have to be aligned to the channel type)
+    let mut pixels: Vec<P> = vec![P::zeroed(); bytes.len() / std::mem::size_of::<P>()];
+    cast_slice_mut::<P, u8>(&mut pixels).copy_from_slice(bytes);
+    pixels
+}
+
+fn convert_pixels<P: Pixel, Q: Pixel>(pixels: &[P]) -> Vec<Q> {
+    // converts a slice of pixels to a vector of pixels of another format
+    pixels.iter().map(|pixel| pixel.convert()).collect()
+}
+
+fn swap_16_bit(bytes: &[u8]) -> Vec<u8> {
+    // converts 16 bit channels between big-endian byte order (used by PNG) and native byte order
+    bytes.chunks_exact(2).flat_map(|channel| u16::from_be_bytes([channel[0], channel[1]]).to_ne_bytes()).collect()
+}
+
+fn read_png(path: &str) -> Result<(png::OutputInfo, Vec<u8>), &'static str> {
+    // reads the first frame of the PNG file, returns its information and bytes
+    match File::open(path) {
+        Ok(file) =>
+            {
+                let mut decoder = png::Decoder::new(file);
+                // bit depths lower than 8 are expanded to 8
+                decoder.set_transformations(png::Transformations::EXPAND);
+                match decoder.read_info() {
+                    Ok(information) =>
+                        {
+                            let mut reader = information;
+                            // Allocate the output buffer.
+                            let mut buf = vec![0; reader.output_buffer_size()];
+                            // Read the next frame. An APNG might contain multiple frames.
+                            match reader.next_frame(&mut buf) {
+                                Ok(info) =>
+                                    {
+                                        // Grab the bytes of the image.
+                                        buf.truncate(info.buffer_size());
+                                        Ok((info, buf))
+                                    },
+                                Err(_) => Err("Can't read file!")
+                            }
+                        },
+                    Err(_) => Err("Can't read file!"),
+                }
+            },
+        Err(_) => Err("Can't open file!"),
+    }
+}
 
+fn write_png(path: &str, width: usize, height: usize, color_type: ColorType, bit_depth: usize, bytes: &[u8]) -> Result<(), &'static str> {
+    // saves the bytes (in native byte order) as PNG file with the given color type and bit depth (8 or 16)
+    let path = std::path::Path::new(path);
+    let color_type: png::ColorType = match color_type {
+        ColorType::Gray => png::ColorType::Grayscale,
+        ColorType::GrayAlpha => png::ColorType::GrayscaleAlpha,
+        ColorType::Rgb => png::ColorType::Rgb,
+        ColorType::Rgba => png::ColorType::Rgba,
+    };
+
+    match File::create(path) {
+        Ok(new_file) =>
+            {
+                let file = new_file;
+                let w = BufWriter::new(file);
+
+                let mut encoder = png::Encoder::new(w, width as u32, height as u32);
+                encoder.set_color(color_type);
+                encoder.set_depth(if bit_depth == 16 { png::BitDepth::Sixteen } else { png::BitDepth::Eight });
+
+                match encoder.write_header() {
+                    Ok(mut writer) =>
+                        {
+                            let result = if bit_depth == 16 {
+                                writer.write_image_data(&swap_16_bit(bytes))
+                            } else {
+                                writer.write_image_data(bytes)
+                            };
+                            match result {
+                                Ok(_) => Ok(()),
+                                Err(_) => Err("Can't write image to file!")
+                            }
+                        },
+                    Err(_) => Err("Can't write image to file!")
+                }
+            },
+        Err(_) => Err("Can't create file!")
+    }
+}
 
-fn bytes_to_rgb8(bytes: &[u8]) -> Vec<[u8; 3]> {
-    // converts a slice of bytes to a vector of pixels
-    try_cast_slice::<u8, [u8; 3]>(bytes).expect("This shouldn't fail!").to_vec()
+/// Operator used to map linear light values of high dynamic range images to the displayable range (`0.0` to `1.0`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ToneMapping {
+    /// Values above `1.0` are clipped
+    Clamp,
+    /// Reinhard operator (`value / (1 + value)`), compresses highlights without ever clipping them
+    Reinhard,
+    /// Filmic curve (ACES approximation by Krzysztof Narkowicz), with higher contrast than [ToneMapping::Reinhard]
+    Filmic,
 }
 
-fn rgb8_to_bytes(rgb8: &[[u8; 3]]) -> &[u8] {
-    // returns a slice of bytes of a vector (slice) of pixels
-    try_cast_slice(rgb8).expect("This shouldn't fail!")
+impl ToneMapping {
+    fn apply(&self, value: f64) -> f64 {
+        // maps the linear light value to range 0.0 - 1.0
+        let value: f64 = value.max(0.0);
+        match self {
+            ToneMapping::Clamp => value.min(1.0),
+            ToneMapping::Reinhard => value / (1.0 + value),
+            ToneMapping::Filmic => ((value * (2.51 * value + 0.03)) / (value * (2.43 * value + 0.59) + 0.14)).clamp(0.0, 1.0),
+        }
+    }
 }
 
-enum BackgroundRGB8 {
-    // enum that holds image background information for ImageRGB8
-    Color([u8; 3]),
-    Image(Vec<[u8; 3]>)
+enum Background<P> {
+    // enum that holds image background information (P is the pixel type of the image)
+    Color(P),
+    Image(Vec<P>)
 }
 
-/// A struct that holds an RGB image with bit depth of 8
-pub struct ImageRGB8 {
+/// A struct that holds an image with pixels of type `P` (see [Pixel] for supported pixel formats).
+/// Pixels with alpha channel hold straight (not premultiplied) alpha.
+pub struct Image<P: Pixel> {
     /// The width of the image
     pub width: usize,
     /// The height of the image
     pub height: usize,
     /// The image pixel data
-    pub image_data: Vec<[u8; 3]>,
-    background_data: BackgroundRGB8
+    pub image_data: Vec<P>,
+    background_data: Background<P>,
+    blend_mode: BlendMode,
+    linear_blending: bool,
 }
 
-impl ImageRGB8 {
-    pub fn new(width: usize, height: usize, background: [u8; 3]) -> Self {
-        //! Returns a new [ImageRGB8].
+/// An image with one gray channel with bit depth of 8.
+pub type ImageGray8 = Image<[u8; 1]>;
+/// An image with one gray channel with bit depth of 16.
+pub type ImageGray16 = Image<[u16; 1]>;
+/// An image with gray and alpha channels with bit depth of 8.
+pub type ImageGrayA8 = Image<[u8; 2]>;
+/// An RGB image with bit depth of 8.
+pub type ImageRGB8 = Image<[u8; 3]>;
+/// An RGBA image with bit depth of 8.
+pub type ImageRGBA8 = Image<[u8; 4]>;
+/// An image with gray and alpha channels with bit depth of 16.
+pub type ImageGrayA16 = Image<[u16; 2]>;
+/// An RGB image with bit depth of 16.
+pub type ImageRGB16 = Image<[u16; 3]>;
+/// An RGBA image with bit depth of 16.
+pub type ImageRGBA16 = Image<[u16; 4]>;
+/// An RGB image with 32 bit floating point channels holding linear light values (`1.0` is full intensity of standard range, higher values are allowed).
+/// Drawing is blended at full precision, use [Image::tone_map()] to convert it to [ImageRGB8].
+pub type ImageRGB32F = Image<[f32; 3]>;
+
+impl<P: Pixel> Image<P> {
+    pub fn new(width: usize, height: usize, background: P) -> Self {
+        //! Returns a new [Image].
         //! ```width```, ```height``` are image dimensions.
-        //! ```background``` is image's color.
+        //! ```background``` is image's color (use alpha of `0` for a transparent background).
 
-        Self { width, height, image_data: vec![background; width * height], background_data: BackgroundRGB8::Color(background) }
+        Self { width, height, image_data: vec![background; width * height], background_data: Background::Color(background), blend_mode: BlendMode::Normal, linear_blending: false }
     }
 
     pub fn from_png(path: &str) -> Result<Self, &'static str> {
         //! Reads the image from a PNG file.
-        //! Returns [Result] which holds new [ImageRGB8] or [Err] with informative message.
+        //! Returns [Result] which holds new [Image] or [Err] with informative message.
         //! ```path``` is the path to the PNG file.
-        //! The PNG file should be RGB or RGBA with bit depth of 8.
-
-        match File::open(path) {
-            Ok(file) =>
-                {
-                    let decoder = png::Decoder::new(file);
-                    match decoder.read_info() {
-                        Ok(information) =>
-                            {
-                                let mut reader = information;
-                                // Allocate the output buffer.
-                                let mut buf = vec![0; reader.output_buffer_size()];
-                                // Read the next frame. An APNG might contain multiple frames.
-                                match reader.next_frame(&mut buf) {
-                                    Ok(new_information) =>
-                                        {
-                                            let info = new_information;
-                                            // Grab the bytes of the image.
-                                            let bytes: &[u8];
-                                            if info.bit_depth == png::BitDepth::Eight {
-                                                // if image is not RGB panic, if it is RGBA convert to RGB
-                                                match info.color_type {
-                                                    png::ColorType::Rgb => {
-                                                        bytes = &buf[..info.buffer_size()];
-                                                        // return ImageRGB8 struct
-                                                        Ok(Self::from_bytes(info.width as usize, info.height as usize, bytes).expect("This shouldn't fail!"))
-                                                    },
-                                                    png::ColorType::Rgba => {
-                                                        buf.truncate(info.buffer_size());
-                                                        let mut iterator = 1..(buf.len() + 1);
-                                                        buf.retain(|_| iterator.next().expect("This shouldn't fail!") % 4 != 0);
-                                                        bytes = &buf;
-                                                        // return ImageRGB8 struct
-                                                        Ok(Self::from_bytes(info.width as usize, info.height as usize, bytes).expect("This shouldn't fail!"))
-                                                    },
-                                                    _ => Err("Image color not RGB or RGBA!")
-                                                }
-                                            } else {
-                                                Err("Image bit depth is not 8!")
-                                            }
-                                        },
-                                    Err(_) => Err("Can't read file!")
-                                }
-                            },
-                        Err(_) => Err("Can't read file!"),
-                    }
-                },
-            Err(_) => Err("Can't open file!"),
-        }
+        //! The PNG file can be gray, gray with alpha, RGB, RGBA or indexed, it is converted to the pixel format of the image (see [Pixel::convert()]).
+        //! Supported bit depths are 16, 8 and lower (16 bit channels are read at full precision).
+
+        let (info, buf) = read_png(path)?;
+        // read the pixels in the format of the PNG file and convert them
+        let image_data: Vec<P> = match (info.color_type, info.bit_depth) {
+            (png::ColorType::Grayscale, png::BitDepth::Eight) => convert_pixels::<[u8; 1], P>(&bytes_to_pixels(&buf)),
+            (png::ColorType::Grayscale, png::BitDepth::Sixteen) => convert_pixels::<[u16; 1], P>(&bytes_to_pixels(&swap_16_bit(&buf))),
+            (png::ColorType::GrayscaleAlpha, png::BitDepth::Eight) => convert_pixels::<[u8; 2], P>(&bytes_to_pixels(&buf)),
+            (png::ColorType::GrayscaleAlpha, png::BitDepth::Sixteen) => convert_pixels::<[u16; 2], P>(&bytes_to_pixels(&swap_16_bit(&buf))),
+            (png::ColorType::Rgb, png::BitDepth::Eight) => convert_pixels::<[u8; 3], P>(&bytes_to_pixels(&buf)),
+            (png::ColorType::Rgb, png::BitDepth::Sixteen) => convert_pixels::<[u16; 3], P>(&bytes_to_pixels(&swap_16_bit(&buf))),
+            (png::ColorType::Rgba, png::BitDepth::Eight) => convert_pixels::<[u8; 4], P>(&bytes_to_pixels(&buf)),
+            (png::ColorType::Rgba, png::BitDepth::Sixteen) => convert_pixels::<[u16; 4], P>(&bytes_to_pixels(&swap_16_bit(&buf))),
+            _ => return Err("Image color type or bit depth is not supported!")
+        };
+        Ok(Self { width: info.width as usize, height: info.height as usize, image_data: image_data.clone(), background_data: Background::Image(image_data), blend_mode: BlendMode::Normal, linear_blending: false })
     }
 
     pub fn from_bytes(width: usize, height: usize, bytes: &[u8]) -> Result<Self, &'static str> {
-        //! Returns [Result] with new [ImageRGB8] or [Err] with informative message.
-        //! It is constructed from ```width```, ```height``` and ```bytes```
+        //! Returns [Result] with new [Image] or [Err] with informative message.
+        //! It is constructed from ```width```, ```height``` and ```bytes``` (channels in native byte order, with straight alpha).
 
-        if width * height * 3 != bytes.len() {
+        if width * height * std::mem::size_of::<P>() != bytes.len() {
             // if number of bytes doesn't match expected number of bytes, panic
-            Err("Number of bytes does not match an RGB image with given dimensions!")
+            Err("Number of bytes does not match an image with given dimensions!")
         } else {
-            // generate RGB image from bytes separately as it needs to be cloned as two separate instances are needed
-            let img = bytes_to_rgb8(bytes);
-            Ok(Self { width, height, image_data: img.clone(), background_data: BackgroundRGB8::Image(img) })
+            // generate image from bytes separately as it needs to be cloned as two separate instances are needed
+            let img: Vec<P> = bytes_to_pixels(bytes);
+            Ok(Self { width, height, image_data: img.clone(), background_data: Background::Image(img), blend_mode: BlendMode::Normal, linear_blending: false })
         }
     }
 
+    pub fn from_premultiplied_bytes(width: usize, height: usize, bytes: &[u8]) -> Result<Self, &'static str> {
+        //! Returns [Result] with new [Image] or [Err] with informative message.
+        //! It is constructed from ```width```, ```height``` and ```bytes``` (channels in native byte order, with premultiplied alpha).
+
+        let mut image: Self = Self::from_bytes(width, height, bytes)?;
+        image.image_data.iter_mut().for_each(|pixel| *pixel = pixel.unpremultiplied());
+        image.background_data = Background::Image(image.image_data.clone());
+        Ok(image)
+    }
+
     pub fn to_png(&self, path: &str) -> Result<(), &'static str> {
         //! Saves the image as PNG.
         //! ```path``` is a path + filename where it will be saved.
+        //! The image is saved with the color type and bit depth of its pixels (floating point images are clamped and saved with bit depth of 8, see [Image::tone_map()]).
         //! Returns [Ok] if everything goes well, or [Err] with description of the error.
-        let path = Path::new(path);
-
-        match File::create(path) {
-            Ok(new_file) =>
-                {
-                    let file = new_file;
-                    let w = BufWriter::new(file);
-
-                    let mut encoder = png::Encoder::new(w, self.width as u32, self.height as u32);
-                    encoder.set_color(png::ColorType::Rgb);
-                    encoder.set_depth(png::BitDepth::Eight);
-
-                    match encoder.write_header() {
-                        Ok(mut writer) =>
-                            {
-                                match writer.write_image_data(self.to_bytes()) {
-                                    Ok(_) => Ok(()),
-                                    Err(_) => Err("Can't write image to file!")
-                                }
-                            },
-                        Err(_) => Err("Can't write image to file!")
-                    }
-                },
-            Err(_) => Err("Can't create file!")
+
+        if P::Channel::BITS <= 16 {
+            write_png(path, self.width, self.height, P::COLOR_TYPE, P::Channel::BITS, self.to_bytes())
+        } else {
+            // convert the pixels to the same color type with bit depth of 8
+            let bytes: Vec<u8> = match P::COLOR_TYPE {
+                ColorType::Gray => cast_slice(&convert_pixels::<P, [u8; 1]>(&self.image_data)).to_vec(),
+                ColorType::GrayAlpha => cast_slice(&convert_pixels::<P, [u8; 2]>(&self.image_data)).to_vec(),
+                ColorType::Rgb => cast_slice(&convert_pixels::<P, [u8; 3]>(&self.image_data)).to_vec(),
+                ColorType::Rgba => cast_slice(&convert_pixels::<P, [u8; 4]>(&self.image_data)).to_vec(),
+            };
+            write_png(path, self.width, self.height, P::COLOR_TYPE, 8, &bytes)
         }
     }
 
     pub fn to_bytes(&self) -> &[u8] {
-        //! Returns a slice of bytes of the ```image_data```
-        rgb8_to_bytes(&self.image_data)
+        //! Returns a slice of bytes of the ```image_data``` (channels in native byte order, with straight alpha).
+        cast_slice(&self.image_data)
+    }
+
+    pub fn to_premultiplied_bytes(&self) -> Vec<u8> {
+        //! Returns bytes of the ```image_data``` with premultiplied alpha (color channels are multiplied by alpha).
+        let pixels: Vec<P> = self.image_data.iter().map(|pixel| pixel.premultiplied()).collect();
+        cast_slice(&pixels).to_vec()
     }
 
-    pub fn get_pixel(&self, x: usize, y: usize) -> Result<[u8; 3], &'static str> {
-        //! Returns an RGB value of the specified pixel if that pixel exists.
+    pub fn convert<Q: Pixel>(&self) -> Image<Q> {
+        //! Returns a copy of the image converted to another pixel format (see [Pixel::convert()]).
+
+        let background_data: Background<Q> = match &self.background_data {
+            Background::Color(color) => Background::Color(color.convert()),
+            Background::Image(img) => Background::Image(convert_pixels(img)),
+        };
+        Image { width: self.width, height: self.height, image_data: convert_pixels(&self.image_data), background_data, blend_mode: self.blend_mode, linear_blending: self.linear_blending }
+    }
+
+    pub fn get_pixel(&self, x: usize, y: usize) -> Result<P, &'static str> {
+        //! Returns the value of the specified pixel if that pixel exists.
 
         if x >= self.width || y >= self.height {
             Err("Given coordinates exceed image limits!")
@@ -159,1228 +269,490 @@ impl ImageRGB8 {
         }
     }
 
-    pub fn set_pixel(&mut self, x: usize, y: usize, color: [u8; 3]) {
+    pub fn set_pixel(&mut self, x: usize, y: usize, color: P) {
         //! Changes the specified pixel to the given ```color```.
         //! If the pixel doesn't exist, does nothing.
 
-        if x < self.width || y < self.width {
+        if x < self.width && y < self.height {
             self.image_data[self.width * (self.height - 1 - y) + x] = color;
         }
     }
 
     pub fn clear(&mut self) {
-        //! Clears ```image_data``` of any drawings (resets it to the state it was in when [ImageRGB8] was created, unless [ImageRGB8::set_background_color()] was used).
+        //! Clears ```image_data``` of any drawings (resets it to the state it was in when [Image] was created, unless [Image::set_background_color()] was used).
 
         match &self.background_data {
-            BackgroundRGB8::Color(color) => self.image_data.fill(*color),
-            BackgroundRGB8::Image(img) => self.image_data = img.clone(),
+            Background::Color(color) => self.image_data.fill(*color),
+            Background::Image(img) => self.image_data = img.clone(),
         }
     }
 
-    pub fn set_background_color(&mut self, color: [u8; 3]) {
+    pub fn set_background_color(&mut self, color: P) {
         //! Sets a new color that will be used as background.
-        //! This only changes internal background data, if you want to apply this to image, call [ImageRGB8::clear()] after this.
+        //! This only changes internal background data, if you want to apply this to image, call [Image::clear()] after this.
+
+        self.background_data = Background::Color(color);
+    }
+
+    pub fn set_blend_mode(&mut self, mode: BlendMode) {
+        //! Sets the blend mode that all following drawing uses to mix the colors of the shapes with the image (see [BlendMode]).
+        //! New images use [BlendMode::Normal].
+
+        self.blend_mode = mode;
+    }
 
-        self.background_data = BackgroundRGB8::Color(color);
+    pub fn set_linear_blending(&mut self, linear_blending: bool) {
+        //! Sets whether all following drawing blends colors in linear light (gamma-correct) instead of directly on the sRGB encoded values (see [Pixel::blend_linear()]).
+        //! Blending in linear light makes anti-aliased edges and thin lines look as bright as they should and translucent colors mix without getting too dark.
+        //! New images blend sRGB encoded values, floating point images always blend in linear light.
+
+        self.linear_blending = linear_blending;
+    }
+
+    fn paint_pixel(&mut self, x: usize, y: usize, paint: &Paint<P>, opacity: f64) {
+        // blends the color of the paint at the pixel x, y (which has to exist) into it with the given opacity
+        let opacity: f64 = opacity * paint.coverage_at(x as f64, y as f64);
+        if opacity > 0.0 {
+            let ind: usize = self.width * (self.height - 1 - y) + x;
+            if self.linear_blending {
+                self.image_data[ind].blend_linear(paint.color_at(x as f64, y as f64), opacity, self.blend_mode);
+            } else {
+                self.image_data[ind].blend_with(paint.color_at(x as f64, y as f64), opacity, self.blend_mode);
+            }
+        }
     }
 
     #[allow(clippy::too_many_arguments)]
-    pub fn draw_line(&mut self, x1: usize, y1: usize, x2: usize, y2: usize, color: [u8; 3], thickness: usize, opacity: f64) {
+    pub fn draw_line(&mut self, x1: f64, y1: f64, x2: f64, y2: f64, color: impl Into<Paint<P>>, thickness: usize, opacity: f64) {
         //! Draws a new line. `x1`, `y1` are coordinates of the starting point. `x2`, `y2` are coordinates of the ending point.
-        //! `color` defines the color of the line.
-        //! `thickness` defines how thick the line will be. (currently doesn't do anything). If set to 0, nothing will be drawn.
+        //! `color` defines the color of the line, which can also be a [Paint] like a [Gradient](crate::Gradient) (its alpha, if it has one, is multiplied with `opacity`).
+        //! `thickness` defines how thick the line will be (measured perpendicular to the line, centered on it). If set to 0, nothing will be drawn.
+        //! The ends of the line are cut perpendicular to it at the starting and the ending point (like [LineCap::Butt]).
+        //! So unlike the corners of rectangles, circles and polygons, the pixels at the end points are only half covered (a 1 pixel thick line ends with pixels at half of the `color`, `128` of `255`).
         //! `opacity` sets the transparency of the line. `<= 0.0` means the line will be completely transparent, while `>= 1.0` means the line won't be transparent.
 
-        if (thickness != 0) && (opacity >= 0.0) {
-            if (x1 == x2) && (x1 < self.width) {
-                // if line is vertical just draw it
-                let lower_y: usize = if min(y1, y2) >= self.height {
-                    self.height - 1
-                } else {
-                    min(y1, y2)
-                };
-                let upper_y: usize = if max(y1, y2) >= self.height {
-                    self.height - 1
-                } else {
-                    max(y1, y2)
-                };
-                if opacity >= 1.0 {
-                    for y in lower_y..(upper_y + 1) {
-                        self.image_data[self.width * (self.height - 1 - y) + x1] = color;
-                    }
-                } else {
-                    for y in lower_y..(upper_y + 1) {
-                        for channel in 0..color.len() {
-                            self.image_data[self.width * (self.height - 1 - y) + x1][channel] = ((self.image_data[self.width * (self.height - 1 - y) + x1][channel] as f64) * (1.0 - opacity) + (color[channel] as f64) * opacity).round() as u8;
-                        }
-                    }
-                }
-            } else if (y1 == y2) && (y1 < self.height) {
-                // if line is horizontal, just draw it
-                let lower_x: usize = if min(x1, x2) >= self.height {
-                    self.height - 1
-                } else {
-                    min(x1, x2)
-                };
-                let upper_x: usize = if max(x1, x2) >= self.height {
-                    self.height - 1
-                } else {
-                    max(x1, x2)
-                };
-                let row: usize = self.width * (self.height - 1 - y1);
-                if opacity >= 1.0 {
-                    self.image_data[(row + lower_x)..(row + upper_x + 1)].fill(color);
-                } else {
-                    for ind in (row + lower_x)..(row + upper_x + 1) {
-                        for channel in 0..color.len() {
-                            self.image_data[ind][channel] = ((self.image_data[ind][channel] as f64) * (1.0 - opacity) + (color[channel] as f64) * opacity).round() as u8;
-                        }
-                    }
-                }
-            } else {
-                // line is diagonal here
-                let slope: f64 = ((y1 as f64) - (y2 as f64)) / ((x1 as f64) - (x2 as f64));
-                let mut x1_calc: usize = x1;
-                let mut x2_calc: usize = x2;
-                let mut y1_calc: usize = y1;
-                let mut y2_calc: usize = y2;
-                if (x1 >= self.width) && (y1 >= self.height) {
-                    let y_temp: usize = self.height - 1;
-                    let x_temp: usize = ((((y_temp as f64) - (y1 as f64)) / slope) + (x1 as f64)).floor() as usize;
-                    if x_temp >= self.width {
-                        let x_temp: usize = self.width - 1;
-                        let y_temp: usize = (slope * ((x_temp as f64) - (x1 as f64)) + (y1 as f64)).floor() as usize;
-                        if y_temp >= self.height {
-                            return
-                        } else {
-                            x1_calc = x_temp;
-                            y1_calc = y_temp;
-                        }
-                    } else {
-                        x1_calc = x_temp;
-                        y1_calc = y_temp;
-                    }
-                } else if x1 >= self.width {
-                    let x_temp: usize = self.width - 1;
-                    let y_temp: usize = (slope * ((x_temp as f64) - (x1 as f64)) + (y1 as f64)).floor() as usize;
-                    if y_temp >= self.height {
-                        return
-                    } else {
-                        x1_calc = x_temp;
-                        y1_calc = y_temp;
-                    }
-                } else if y1 >= self.height {
-                    let y_temp: usize = self.height - 1;
-                    let x_temp: usize = ((((y_temp as f64) - (y1 as f64)) / slope) + (x1 as f64)).floor() as usize;
-                    if x_temp >= self.width {
-                        return
-                    } else {
-                        x1_calc = x_temp;
-                        y1_calc = y_temp;
-                    }
-                }
-                if (x2 >= self.width) && (y2 >= self.height) {
-                    let y_temp: usize = self.height - 1;
-                    let x_temp: usize = ((((y_temp as f64) - (y1 as f64)) / slope) + (x1 as f64)).floor() as usize;
-                    if x_temp >= self.width {
-                        let x_temp: usize = self.width - 1;
-                        let y_temp: usize = (slope * ((x_temp as f64) - (x1 as f64)) + (y1 as f64)).floor() as usize;
-                        if y_temp >= self.height {
-                            return
-                        } else {
-                            x2_calc = x_temp;
-                            y2_calc = y_temp;
-                        }
-                    } else {
-                        x2_calc = x_temp;
-                        y2_calc = y_temp;
-                    }
-                } else if x2 >= self.width {
-                    let x_temp: usize = self.width - 1;
-                    let y_temp: usize = (slope * ((x_temp as f64) - (x1 as f64)) + (y1 as f64)).floor() as usize;
-                    if y_temp >= self.height {
-                        return
-                    } else {
-                        x2_calc = x_temp;
-                        y2_calc = y_temp;
-                    }
-                } else if y2 >= self.height {
-                    let y_temp: usize = self.height - 1;
-                    let x_temp: usize = ((((y_temp as f64) - (y1 as f64)) / slope) + (x1 as f64)).floor() as usize;
-                    if x_temp >= self.width {
-                        return
-                    } else {
-                        x2_calc = x_temp;
-                        y2_calc = y_temp;
-                    }
-                }
-                if (x1_calc == x2_calc) && (y1_calc == y2_calc) {
-                    return
-                }
-                // if line has slope use Xiaolin Wu's algorithm to draw it anti aliased
-                // if slope is more horizontal (<= 1), antialiasing with pixels above and below
-                // if slope is more vertical (> 1), antialiasing with pixels left and right
-                if slope.abs() <= 1.0 {
-                    for x in min(x1_calc, x2_calc)..(max(x1_calc, x2_calc) + 1) {
-                        let y: f64 = slope * ((x - x1) as f64) + (y1 as f64);
-                        if (y - y.round()).abs() < 0.00001 {
-                            // if point is very close to integer, just draw it on that pixel
-                            if opacity >= 1.0 {
-                                self.image_data[self.width * (self.height - 1 - (y.round() as usize)) + x] = color;
-                            } else {
-                                let ind: usize = self.width * (self.height - 1 - (y.round() as usize)) + x;
-                                for channel in 0..color.len() {
-                                    self.image_data[ind][channel] = ((self.image_data[ind][channel] as f64) * (1.0 - opacity) + (color[channel] as f64) * opacity).round() as u8;
-                                }
-                            }
-                        } else {
-                            // split point between two pixels
-                            let mut pix1_percentage: f64 = y - y.floor();
-                            let mut pix2_percentage: f64 = 1.0 - pix1_percentage;
-                            if opacity < 1.0 {
-                                pix1_percentage *= opacity;
-                                pix2_percentage *= opacity
-                            }
-                            let pix1_ind: usize = self.width * (self.height - 1 - (y.ceil() as usize)) + x;
-                            let pix2_ind: usize = pix1_ind + self.width;
-                            for channel in 0..color.len() {
-                                // background color aware ===> color = color + (new_color - color) * color_percentage ===> color = color * (1 - color_percentage) + new_color * color_percentage
-                                self.image_data[pix1_ind][channel] = ((self.image_data[pix1_ind][channel] as f64) * (1.0 - pix1_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-                                self.image_data[pix2_ind][channel] = ((self.image_data[pix2_ind][channel] as f64) * (1.0 - pix2_percentage) + (color[channel] as f64) * pix2_percentage).round() as u8;
-                            }
-                        }
-                    }
-                } else {
-                    for y in min(y1_calc, y2_calc)..(max(y1_calc, y2_calc) + 1) {
-                        let x: f64 = (((y - y1) as f64) / slope) + (x1 as f64);
-                        if (x - x.round()).abs() < 0.00001 {
-                            // if point is very close to integer, just draw it on that pixel
-                            if opacity >= 1.0 {
-                                self.image_data[self.width * (self.height - 1 - y) + (x.round() as usize)] = color;
-                            } else {
-                                let ind: usize = self.width * (self.height - 1 - y) + (x.round() as usize);
-                                for channel in 0..color.len() {
-                                    self.image_data[ind][channel] = ((self.image_data[ind][channel] as f64) * (1.0 - opacity) + (color[channel] as f64) * opacity).round() as u8;
-                                }
-                            }
-                        } else {
-                            // split point between two pixels
-                            let mut pix1_percentage: f64 = x.ceil() - x;
-                            let mut pix2_percentage: f64 = 1.0 - pix1_percentage;
-                            if opacity < 1.0 {
-                                pix1_percentage *= opacity;
-                                pix2_percentage *= opacity
-                            }
-                            let pix1_ind: usize = self.width * (self.height - 1 - y) + (x.floor() as usize);
-                            let pix2_ind: usize = pix1_ind + 1;
-                            for channel in 0..color.len() {
-                                // background color aware ===> color = color + (new_color - color) * color_percentage ===> color = color * (1 - color_percentage) + new_color * color_percentage
-                                self.image_data[pix1_ind][channel] = ((self.image_data[pix1_ind][channel] as f64) * (1.0 - pix1_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-                                self.image_data[pix2_ind][channel] = ((self.image_data[pix2_ind][channel] as f64) * (1.0 - pix2_percentage) + (color[channel] as f64) * pix2_percentage).round() as u8;
-                            }
-                        }
-                    }
-                }
-            }
+        if opacity >= 0.0 {
+            let paint: Paint<P> = color.into();
+            raster::line(self.width, self.height, x1, y1, x2, y2, thickness, &mut |x, y, coverage| self.paint_pixel(x, y, &paint, coverage * opacity));
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_dashed_line(&mut self, x1: f64, y1: f64, x2: f64, y2: f64, dashes: &[f64], dash_offset: f64, cap: LineCap, color: impl Into<Paint<P>>, thickness: usize, opacity: f64) {
+        //! Draws a new dashed line. `x1`, `y1` are coordinates of the starting point. `x2`, `y2` are coordinates of the ending point.
+        //! `dashes` are the lengths (in pixels) of the dashes and the gaps between them, starting from the starting point, and `dash_offset` defines how far into the pattern the line starts (see [Path::dashed()]).
+        //! `cap` defines the shape of the ends of the dashes (see [LineCap], use [LineCap::Round] with dashes of length `0.0` for a dotted line).
+        //! Without `dashes` and with [LineCap::Butt] the line is the same as the one drawn by [Image::draw_line()].
+        //! `color` defines the color of the line, which can also be a [Paint] like a [Gradient](crate::Gradient) (its alpha, if it has one, is multiplied with `opacity`).
+        //! `thickness` defines how thick the line will be (measured perpendicular to the line, centered on it). If set to 0, nothing will be drawn.
+        //! `opacity` sets the transparency of the line. `<= 0.0` means the line will be completely transparent, while `>= 1.0` means the line won't be transparent.
+
+        if opacity >= 0.0 {
+            let paint: Paint<P> = color.into();
+            let path: Path = Path::polyline(&[(x1, y1), (x2, y2)]).dashed(dashes, dash_offset);
+            raster::stroke_path(self.width, self.height, &path, thickness, LineJoin::Miter, cap, &mut |x, y, coverage| self.paint_pixel(x, y, &paint, coverage * opacity));
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_arrow(&mut self, x1: f64, y1: f64, x2: f64, y2: f64, head: ArrowHead, head_length: f64, head_angle: f64, double_ended: bool, color: impl Into<Paint<P>>, thickness: usize, opacity: f64) {
+        //! Draws a new arrow. `x1`, `y1` are coordinates of the starting point. `x2`, `y2` are coordinates of the ending point, where the tip of the head is.
+        //! `head` defines the shape of the head (see [ArrowHead]).
+        //! `head_length` defines the length of the sides of the head and `head_angle` (in radians) the angle between the sides and the line (`PI / 6` is a common choice).
+        //! The line is the same as the one drawn by [Image::draw_line()], so with `head_length` of `0.0` the arrow is only the line.
+        //! `double_ended` defines whether there is a head at the starting point too.
+        //! `color` defines the color of the arrow, which can also be a [Paint] like a [Gradient](crate::Gradient) (its alpha, if it has one, is multiplied with `opacity`).
+        //! `thickness` defines how thick the line and the sides of an open head will be (measured perpendicular to them, centered on them). If set to 0, nothing will be drawn.
+        //! `opacity` sets the transparency of the arrow. `<= 0.0` means the arrow will be completely transparent, while `>= 1.0` means the arrow won't be transparent.
+        //! Every pixel is blended only once, so the parts where the head overlaps the line are not darker.
+
+        if opacity >= 0.0 {
+            let paint: Paint<P> = color.into();
+            raster::arrow(self.width, self.height, x1, y1, x2, y2, head, head_length, head_angle, double_ended, thickness, &mut |x, y, coverage| self.paint_pixel(x, y, &paint, coverage * opacity));
         }
     }
 
     #[allow(clippy::too_many_arguments)]
-    #[allow(clippy::collapsible_else_if)]
-    pub fn draw_rectangle(&mut self, x1: usize, y1: usize, x2: usize, y2: usize, color: [u8; 3], thickness: usize, opacity: f64) {
+    pub fn draw_rectangle(&mut self, x1: f64, y1: f64, x2: f64, y2: f64, color: impl Into<Paint<P>>, thickness: usize, opacity: f64) {
         //! Draws a new rectangle. `x1`, `y1` are the coordinates of the first corner, and `x2`, `y2` are the coordinates of the opposite corner.
-        //! `color` defines the color of the rectangle.
+        //! `color` defines the color of the rectangle, which can also be a [Paint] like a [Gradient](crate::Gradient) (its alpha, if it has one, is multiplied with `opacity`).
         //! `thickness` defines how thick the rectangle will be. (thickness is added to the inside of the rectangle). If set to 0, the rectangle will be filled.
         //! `opacity` sets the transparency of the rectangle. `<= 0.0` means the rectangle will be completely transparent, while `>= 1.0` means the rectangle won't be transparent.
 
-        // if opacity is 0.0, or less, then rectangle is transparent, nothing is to be drawn.
         if opacity >= 0.0 {
+            let paint: Paint<P> = color.into();
+            raster::rectangle(self.width, self.height, x1, y1, x2, y2, thickness, &mut |x, y, coverage| self.paint_pixel(x, y, &paint, coverage * opacity));
+        }
+    }
 
-            // find corners
-            let mut smaller_x = min(x1, x2);
-            let mut bigger_x = max(x1, x2);
-            let mut smaller_y = min(y1, y2);
-            let mut bigger_y = max(y1, y2);
-            if bigger_x >= self.width {
-                bigger_x = self.width - 1;
-            }
-            if bigger_y >= self.height {
-                bigger_y = self.height - 1;
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_dashed_rectangle(&mut self, x1: f64, y1: f64, x2: f64, y2: f64, dashes: &[f64], dash_offset: f64, cap: LineCap, color: impl Into<Paint<P>>, thickness: usize, opacity: f64) {
+        //! Draws a new rectangle with a dashed outline. `x1`, `y1` are the coordinates of the first corner, and `x2`, `y2` are the coordinates of the opposite corner.
+        //! `dashes` are the lengths (in pixels) of the dashes and the gaps between them, starting from the bottom left corner and going counterclockwise,
+        //! and `dash_offset` defines how far into the pattern the outline starts (see [Path::dashed()]).
+        //! `cap` defines the shape of the ends of the dashes (see [LineCap]).
+        //! Without gaps in `dashes` the outline is the same as the one drawn by [Image::draw_rectangle()].
+        //! `color` defines the color of the rectangle, which can also be a [Paint] like a [Gradient](crate::Gradient) (its alpha, if it has one, is multiplied with `opacity`).
+        //! `thickness` defines how thick the outline will be. (thickness is added to the inside of the rectangle). If set to 0, nothing will be drawn.
+        //! `opacity` sets the transparency of the rectangle. `<= 0.0` means the rectangle will be completely transparent, while `>= 1.0` means the rectangle won't be transparent.
+
+        if !has_gaps(dashes) {
+            // outline without gaps is the solid outline
+            if thickness != 0 {
+                self.draw_rectangle(x1, y1, x2, y2, color, thickness, opacity);
             }
+        } else if opacity >= 0.0 {
+            let paint: Paint<P> = color.into();
+            // center of the outline lies (thickness - 1) / 2 inside of the rectangle, so it covers the same pixels as the solid outline
+            let path: Path = Path::rectangle_outline(x1, y1, x2, y2, (thickness as f64 - 1.0) / 2.0).dashed(dashes, dash_offset);
+            raster::stroke_path(self.width, self.height, &path, thickness, LineJoin::Miter, cap, &mut |x, y, coverage| self.paint_pixel(x, y, &paint, coverage * opacity));
+        }
+    }
 
-            if thickness == 0 {
-                if opacity >= 1.0 {
-                    // Draw filled, solid rectangle.
-                    // draws line by line
-                    for y in smaller_y..(bigger_y + 1) {
-                        let base_location = self.width * (self.height - 1 - y);
-                        self.image_data[(base_location + smaller_x)..(base_location + bigger_x + 1)].fill(color);
-                    }
-                } else {
-                    // Draw filled, transparent rectangle.
-                    // draws each pixel by blending it to the background (because of transparency)
-                    let reverse_opacity = 1.0 - opacity;
-                    for y in smaller_y..(bigger_y + 1) {
-                        let base_location = self.width * (self.height - 1 - y);  // base index of line
-                        for x in (base_location + smaller_x)..(base_location + bigger_x + 1) { // range of indexes on that line (horizontal line)
-                            for channel in 0..color.len() {
-                                // background color aware ===> color = color + (new_color - color) * color_percentage ===> color = color * (1 - color_percentage) + new_color * color_percentage
-                                self.image_data[x][channel] = ((self.image_data[x][channel] as f64) * reverse_opacity + (color[channel] as f64) * opacity).round() as u8;
-                            }
-                        }
-                    }
-                }
-            } else {
-                if opacity >= 1.0 {
-                    // Draw rectangle, solid
-
-                    let mut used_thickness = thickness;  // new thickness variable
-                    // limits maximum thickness
-                    let limit_x = ((bigger_x - smaller_x) / 2) + 1;
-                    let limit_y = ((bigger_y - smaller_y) / 2) + 1;
-                    if (thickness > limit_x) || (thickness > limit_y) {
-                        used_thickness = min(limit_x, limit_y);
-                    }
-
-                    // draw smaller and smaller rectangles until given thickness is achieved
-                    while used_thickness > 0 {
-                        used_thickness -= 1;
-
-                        // draw horizontal sides
-                        self.image_data[(self.width * (self.height - 1 - smaller_y) + smaller_x)..(self.width * (self.height - 1 - smaller_y) + bigger_x + 1)].fill(color);
-                        self.image_data[(self.width * (self.height - 1 - bigger_y) + smaller_x)..(self.width * (self.height - 1 - bigger_y) + bigger_x + 1)].fill(color);
-                        // draw vertical sides
-                        for y in (smaller_y + 1)..bigger_y {
-                            let base_location = self.width * (self.height - 1 - y);
-                            self.image_data[base_location + smaller_x] = color;
-                            self.image_data[base_location + bigger_x] = color;
-                        }
-
-                        smaller_x += 1;
-                        smaller_y += 1;
-                        bigger_x -= 1;
-                        bigger_y -= 1;
-                    }
-                } else {
-                    // Draw rectangle, transparent
-
-                    let mut used_thickness = thickness; // new variable used for thickness
-                    // limits maximum thickness
-                    let limit_x = ((bigger_x - smaller_x) / 2) + 1;
-                    let limit_y = ((bigger_y - smaller_y) / 2) + 1;
-                    if (thickness > limit_x) || (thickness > limit_y) {
-                        used_thickness = min(limit_x, limit_y);
-                    }
-
-                    let reverse_opacity = 1.0 - opacity;  // no explicit meaning, just used as a value when blending (to reduce unnecessary calculations)
-
-                    while used_thickness > 0 {
-                        used_thickness -= 1;
-
-                        // draw horizontal sides
-                        for y in [smaller_y, bigger_y] {  // bottom and top side
-                            let base_location = self.width * (self.height - 1 - y);  // starting index of line where sides are
-                            for x in (base_location + smaller_x)..(base_location + bigger_x + 1) {  // pixels in those sides
-                                for channel in 0..color.len() {
-                                    // background color aware ===> color = color + (new_color - color) * color_percentage ===> color = color * (1 - color_percentage) + new_color * color_percentage
-                                    self.image_data[x][channel] = ((self.image_data[x][channel] as f64) * reverse_opacity + (color[channel] as f64) * opacity).round() as u8;
-                                }
-                            }
-                        }
-
-                        // draw vertical sides
-                        for y in (smaller_y + 1)..bigger_y {
-                            let base_location = self.width * (self.height - 1 - y);
-                            for x in [smaller_x, bigger_x] {
-                                let ind_location = base_location + x;
-                                for channel in 0..color.len() {
-                                    // background color aware ===> color = color + (new_color - color) * color_percentage ===> color = color * (1 - color_percentage) + new_color * color_percentage
-                                    self.image_data[ind_location][channel] = ((self.image_data[ind_location][channel] as f64) * reverse_opacity + (color[channel] as f64) * opacity).round() as u8;
-                                }
-                            }
-                        }
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_rotated_rectangle(&mut self, x1: f64, y1: f64, x2: f64, y2: f64, angle: f64, color: impl Into<Paint<P>>, thickness: usize, opacity: f64) {
+        //! Draws a new rotated rectangle. `x1`, `y1` are the coordinates of the first corner, and `x2`, `y2` are the coordinates of the opposite corner of the rectangle before it is rotated.
+        //! `angle` (in radians) defines the counterclockwise rotation of the rectangle around its center.
+        //! `color` defines the color of the rectangle, which can also be a [Paint] like a [Gradient](crate::Gradient) (its alpha, if it has one, is multiplied with `opacity`).
+        //! `thickness` defines how thick the rectangle will be. (thickness is added to the inside of the rectangle). If set to 0, the rectangle will be filled.
+        //! `opacity` sets the transparency of the rectangle. `<= 0.0` means the rectangle will be completely transparent, while `>= 1.0` means the rectangle won't be transparent.
 
-                        smaller_x += 1;
-                        smaller_y += 1;
-                        bigger_x -= 1;
-                        bigger_y -= 1;
-                    }
-                }
-            }
+        if opacity >= 0.0 {
+            let paint: Paint<P> = color.into();
+            raster::rounded_rectangle(self.width, self.height, x1, y1, x2, y2, [0.0; 4], angle, thickness, &mut |x, y, coverage| self.paint_pixel(x, y, &paint, coverage * opacity));
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_rounded_rectangle(&mut self, x1: f64, y1: f64, x2: f64, y2: f64, radii: [f64; 4], color: impl Into<Paint<P>>, thickness: usize, opacity: f64) {
+        //! Draws a new rectangle with rounded corners. `x1`, `y1` are the coordinates of the first corner, and `x2`, `y2` are the coordinates of the opposite corner.
+        //! `radii` are the radii of the top left, top right, bottom right and bottom left corner (limited to half of the shorter side of the rectangle, `0.0` is a sharp corner).
+        //! `color` defines the color of the rectangle, which can also be a [Paint] like a [Gradient](crate::Gradient) (its alpha, if it has one, is multiplied with `opacity`).
+        //! `thickness` defines how thick the rectangle will be. (thickness is added to the inside of the rectangle). If set to 0, the rectangle will be filled.
+        //! `opacity` sets the transparency of the rectangle. `<= 0.0` means the rectangle will be completely transparent, while `>= 1.0` means the rectangle won't be transparent.
+
+        if opacity >= 0.0 {
+            let paint: Paint<P> = color.into();
+            raster::rounded_rectangle(self.width, self.height, x1, y1, x2, y2, radii, 0.0, thickness, &mut |x, y, coverage| self.paint_pixel(x, y, &paint, coverage * opacity));
         }
     }
 
-    #[allow(clippy::collapsible_else_if)]
-    pub fn draw_circle(&mut self, x: usize, y: usize, radius: usize, color: [u8; 3], thickness: usize, opacity: f64) {
+    pub fn draw_circle(&mut self, x: f64, y: f64, radius: f64, color: impl Into<Paint<P>>, thickness: usize, opacity: f64) {
         //! Draws a new circle. `x`, `y` are the coordinates of the center of the circle.
         //! `radius` defines the radius of the circle.
-        //! `color` defines the color of the circle.
-        //! `thickness` defines how thick the circle will be. (currently doesn't do anything). If set to 0, the circle will be filled.
+        //! `color` defines the color of the circle, which can also be a [Paint] like a [Gradient](crate::Gradient) (its alpha, if it has one, is multiplied with `opacity`).
+        //! `thickness` defines how thick the circle will be. (thickness is added to the inside of the circle). If set to 0, the circle will be filled.
         //! `opacity` sets the transparency of the circle.
         //! `<= 0.0` means the circle will be completely transparent, while `>= 1.0` means the circle won't be transparent.
 
-        if (radius > 0) && (opacity >= 0.0) && (x < self.width) && (y < self.height) && (x >= radius) && (y >= radius) && (x + radius < self.width) && (y + radius < self.height) {
-            let x0: f64 = x as f64;
-            let y0: f64 = y as f64;
-            let radius_sqrd: f64 = radius.pow(2) as f64;
-
-            let x_upperlimit: usize = x + ((((radius as f64) * FRAC_1_SQRT_2).round()) as usize);  // x up to which draw adjacent pixels vertically
-            let mut y_upperlimit: usize = 0;  // y up to which draw adjacent pixels horizontally (is initialized to 0, but is changed later)
-
-            if thickness == 0 {
-                if opacity >= 1.0 {
-                    // DRAW FILLED SOLID CIRCLE
-
-                    // for every x, calculate y and draw outer pixel, connecting inner pixels with solid line (for filling circle)
-                    let mut previous_y: usize = y + radius;
-                    for x_coord in x..(x_upperlimit + 1) {
-                        let y_coord: f64 = y0 + (radius_sqrd - (x_coord as f64 - x0).powi(2)).sqrt();
-
-                        if x_coord == x_upperlimit {
-                            y_upperlimit = y_coord.floor() as usize;
-                        }
-
-                        if (y_coord - y_coord.round()).abs() < 0.00001 {
-                            // if point is very close to integer, just draw it on that pixel, and mirror to other 3 symmetric pixels
-                            if x_coord == x {
-                                self.image_data[self.width * (self.height - 1 - (y_coord.round() as usize)) + x_coord] = color;
-                                self.image_data[self.width * (self.height - 1 - (2 * y - (y_coord.round() as usize))) + x_coord] = color;
-                            } else if y_coord.round() as usize > y_upperlimit {
-                                self.image_data[(self.width * (self.height - 1 - (y_coord.round() as usize)) + (2 * x - x_coord))..(self.width * (self.height - 1 - (y_coord.round() as usize)) + x_coord + 1)].fill(color);
-                                self.image_data[(self.width * (self.height - 1 - (2 * y - (y_coord.round() as usize))) + (2 * x - x_coord))..(self.width * (self.height - 1 - (2 * y - (y_coord.round() as usize))) + x_coord + 1)].fill(color);
-                                previous_y = y_coord.round() as usize;
-                            }
-                        } else {
-                            // split point between two pixels, and mirror to other 3 symmetric pixels
-                            let pix1_percentage: f64 = y_coord - y_coord.floor();
-                            let pix2_percentage: f64 = 1.0 - pix1_percentage;
-                            let pix1_ind: usize = self.width * (self.height - 1 - (y_coord.ceil() as usize)) + x_coord;
-                            for channel in 0..color.len() {
-                                // background color aware ===> color = color + (new_color - color) * color_percentage ===> color = color * (1 - color_percentage) + new_color * color_percentage
-                                self.image_data[pix1_ind][channel] = ((self.image_data[pix1_ind][channel] as f64) * (pix2_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
+        if opacity >= 0.0 {
+            let paint: Paint<P> = color.into();
+            raster::ellipse(self.width, self.height, x, y, radius, radius, 0.0, thickness, &mut |x, y, coverage| self.paint_pixel(x, y, &paint, coverage * opacity));
+        }
+    }
 
-                                self.image_data[pix1_ind - 2 * (x_coord - x)][channel] = ((self.image_data[pix1_ind - 2 * (x_coord - x)][channel] as f64) * (pix2_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_dashed_circle(&mut self, x: f64, y: f64, radius: f64, dashes: &[f64], dash_offset: f64, cap: LineCap, color: impl Into<Paint<P>>, thickness: usize, opacity: f64) {
+        //! Draws a new circle with a dashed outline. `x`, `y` are the coordinates of the center of the circle.
+        //! `radius` defines the radius of the circle.
+        //! `dashes` are the lengths (in pixels) of the dashes and the gaps between them, starting from the rightmost point and going counterclockwise,
+        //! and `dash_offset` defines how far into the pattern the outline starts (see [Path::dashed()]).
+        //! `cap` defines the shape of the ends of the dashes (see [LineCap]).
+        //! Without gaps in `dashes` the outline is the same as the one drawn by [Image::draw_circle()].
+        //! `color` defines the color of the circle, which can also be a [Paint] like a [Gradient](crate::Gradient) (its alpha, if it has one, is multiplied with `opacity`).
+        //! `thickness` defines how thick the outline will be. (thickness is added to the inside of the circle). If set to 0, nothing will be drawn.
+        //! `opacity` sets the transparency of the circle.
+        //! `<= 0.0` means the circle will be completely transparent, while `>= 1.0` means the circle won't be transparent.
 
-                                self.image_data[pix1_ind + 2 * (y_coord.ceil() as usize - y) * self.width][channel] = ((self.image_data[pix1_ind + 2 * (y_coord.ceil() as usize - y) * self.width][channel] as f64) * (pix2_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
+        self.draw_dashed_ellipse(x, y, radius, radius, dashes, dash_offset, cap, color, thickness, opacity);
+    }
 
-                                self.image_data[pix1_ind + 2 * (y_coord.ceil() as usize - y) * self.width - 2 * (x_coord - x)][channel] = ((self.image_data[pix1_ind + 2 * (y_coord.ceil() as usize - y) * self.width - 2 * (x_coord - x)][channel] as f64) * (pix2_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-                            }
-                            if (y_coord.ceil() as usize) < previous_y {
-                                self.image_data[(pix1_ind - 2 * (x_coord - x) + 1)..pix1_ind].fill(color);
-                                self.image_data[(pix1_ind + 2 * (y_coord.ceil() as usize - y) * self.width - 2 * (x_coord - x) + 1)..(pix1_ind + 2 * (y_coord.ceil() as usize - y) * self.width)].fill(color);
-                                previous_y = y_coord.ceil() as usize
-                            }
-                        }
-                    }
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_ellipse(&mut self, x: f64, y: f64, horizontal_axis: f64, vertical_axis: f64, color: impl Into<Paint<P>>, thickness: usize, opacity: f64) {
+        //! Draws a new ellipse. `x`, `y` are the coordinates of the center of the ellipse.
+        //! `horizontal_axis` defines the half length of the horizontal axis.
+        //! `vertical_axis` defines the half length of the vertical axis.
+        //! `color` defines the color of the ellipse, which can also be a [Paint] like a [Gradient](crate::Gradient) (its alpha, if it has one, is multiplied with `opacity`).
+        //! `thickness` defines how thick the ellipse will be. (thickness is added to the inside of the ellipse). If set to 0, the ellipse will be filled.
+        //! `opacity` sets the transparency of the ellipse.
+        //! `<= 0.0` means the ellipse will be completely transparent, while `>= 1.0` means the ellipse won't be transparent.
 
-                    // for every y, calculate x and draw outer pixel, connecting inner pixels with solid line (for filling circle)
-                    for y_coord in y..(y_upperlimit + 1) {
-                        let x_coord: f64 = x0 + (radius_sqrd - (y_coord as f64 - y0).powi(2)).sqrt();
+        if opacity >= 0.0 {
+            let paint: Paint<P> = color.into();
+            raster::ellipse(self.width, self.height, x, y, horizontal_axis, vertical_axis, 0.0, thickness, &mut |x, y, coverage| self.paint_pixel(x, y, &paint, coverage * opacity));
+        }
+    }
 
-                        if (x_coord - x_coord.round()).abs() < 0.00001 {
-                            // if point is very close to integer, just draw it on that pixel, and mirror to other 3 symmetric pixels
-                            if y_coord == y {
-                                self.image_data[(self.width * (self.height - 1 - y_coord) + (2 * x - x_coord.round() as usize))..(self.width * (self.height - 1 - y_coord) + x_coord.round() as usize + 1)].fill(color);
-                            } else {
-                                self.image_data[(self.width * (self.height - 1 - y_coord) + (2 * x - x_coord.round() as usize))..(self.width * (self.height - 1 - y_coord) + (x_coord.round() as usize) + 1)].fill(color);
-                                self.image_data[(self.width * (self.height - 1 - (2 * y - y_coord)) + (2 * x - x_coord.round() as usize))..(self.width * (self.height - 1 - (2 * y - y_coord)) + (x_coord.round() as usize) + 1)].fill(color);
-                            }
-                        } else {
-                            // split point between two pixels, and mirror to other 3 symmetric pixels
-                            let pix1_percentage: f64 = x_coord - x_coord.floor();
-                            let pix2_percentage: f64 = 1.0 - pix1_percentage;
-                            let pix_ind: usize = self.width * (self.height - 1 - y_coord) + x_coord.ceil() as usize;
-                            for channel in 0..color.len() {
-                                // background color aware ===> color = color + (new_color - color) * color_percentage ===> color = color * (1 - color_percentage) + new_color * color_percentage
-                                self.image_data[pix_ind][channel] = ((self.image_data[pix_ind][channel] as f64) * (pix2_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-
-                                let mut temp_ind = pix_ind - 2 * (x_coord.ceil() as usize - x);
-                                self.image_data[temp_ind][channel] = ((self.image_data[temp_ind][channel] as f64) * (pix2_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-
-                                temp_ind = pix_ind + 2 * (y_coord - y) * self.width;
-                                self.image_data[temp_ind][channel] = ((self.image_data[temp_ind][channel] as f64) * (pix2_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-
-                                temp_ind -= 2 * (x_coord.ceil() as usize - x);
-                                self.image_data[temp_ind][channel] = ((self.image_data[temp_ind][channel] as f64) * (pix2_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-                            }
-                            self.image_data[(pix_ind - 2 * (x_coord.ceil() as usize - x) + 1)..pix_ind].fill(color);
-                            self.image_data[(pix_ind + 2 * (y_coord - y) * self.width - 2 * (x_coord.ceil() as usize - x) + 1)..(pix_ind + 2 * (y_coord - y) * self.width)].fill(color);
-                        }
-                    }
-
-                } else {
-                    // DRAW FILLED TRANSPARENT CIRCLE
-
-                    // for every x, calculate y and draw outer pixel, connecting inner pixels with solid line (for filling circle)
-                    let mut previous_y: usize = y + radius;
-                    for x_coord in x..(x_upperlimit + 1) {
-                        let y_coord: f64 = y0 + (radius_sqrd - (x_coord as f64 - x0).powi(2)).sqrt();
-
-                        if x_coord == x_upperlimit {
-                            y_upperlimit = y_coord.floor() as usize;
-                        }
-
-                        if (y_coord - y_coord.round()).abs() < 0.00001 {
-                            // if point is very close to integer, just draw it on that pixel, and mirror to other 3 symmetric pixels
-                            if x_coord == x {
-                                for channel in 0..color.len() {
-                                    self.image_data[self.width * (self.height - 1 - y_coord.round() as usize) + x_coord][channel] = ((self.image_data[self.width * (self.height - 1 - y_coord.round() as usize) + x_coord][channel] as f64) * (1.0 - opacity) + (color[channel] as f64) * opacity).round() as u8;
-                                    self.image_data[self.width * (self.height - 1 - (2 * y - (y_coord.round() as usize))) + x_coord][channel] = ((self.image_data[self.width * (self.height - 1 - (2 * y - (y_coord.round() as usize))) + x_coord][channel] as f64) * (1.0 - opacity) + (color[channel] as f64) * opacity).round() as u8;
-                                }
-                            } else if y_coord.round() as usize > y_upperlimit {
-                                for pixel_ind in (self.width * (self.height - 1 - (y_coord.round() as usize)) + (2 * x - x_coord))..(self.width * (self.height - 1 - (y_coord.round() as usize)) + x_coord + 1) {
-                                    for channel in 0..color.len() {
-                                        self.image_data[pixel_ind][channel] = ((self.image_data[pixel_ind][channel] as f64) * (1.0 - opacity) + (color[channel] as f64) * opacity).round() as u8;
-                                    }
-                                }
-                                for pixel_ind in (self.width * (self.height - 1 - (2 * y - (y_coord.round() as usize))) + (2 * x - x_coord))..(self.width * (self.height - 1 - (2 * y - (y_coord.round() as usize))) + x_coord + 1) {
-                                    for channel in 0..color.len() {
-                                        self.image_data[pixel_ind][channel] = ((self.image_data[pixel_ind][channel] as f64) * (1.0 - opacity) + (color[channel] as f64) * opacity).round() as u8;
-                                    }
-                                }
-                                previous_y = y_coord.round() as usize;
-                            }
-                        } else {
-                            // split point between two pixels, and mirror to other 3 symmetric pixels
-                            let pix1_percentage: f64 = (y_coord - y_coord.floor()) * opacity;
-                            let pix2_percentage: f64 = 1.0 - pix1_percentage;
-                            let pix1_ind: usize = self.width * (self.height - 1 - (y_coord.ceil() as usize)) + x_coord;
-                            for channel in 0..color.len() {
-                                // background color aware ===> color = color + (new_color - color) * color_percentage ===> color = color * (1 - color_percentage) + new_color * color_percentage
-                                self.image_data[pix1_ind][channel] = ((self.image_data[pix1_ind][channel] as f64) * (pix2_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_dashed_ellipse(&mut self, x: f64, y: f64, horizontal_axis: f64, vertical_axis: f64, dashes: &[f64], dash_offset: f64, cap: LineCap, color: impl Into<Paint<P>>, thickness: usize, opacity: f64) {
+        //! Draws a new ellipse with a dashed outline. `x`, `y` are the coordinates of the center of the ellipse.
+        //! `horizontal_axis` defines the half length of the horizontal axis.
+        //! `vertical_axis` defines the half length of the vertical axis.
+        //! `dashes` are the lengths (in pixels) of the dashes and the gaps between them, starting from the rightmost point and going counterclockwise,
+        //! and `dash_offset` defines how far into the pattern the outline starts (see [Path::dashed()]).
+        //! `cap` defines the shape of the ends of the dashes (see [LineCap]).
+        //! Without gaps in `dashes` the outline is the same as the one drawn by [Image::draw_ellipse()].
+        //! `color` defines the color of the ellipse, which can also be a [Paint] like a [Gradient](crate::Gradient) (its alpha, if it has one, is multiplied with `opacity`).
+        //! `thickness` defines how thick the outline will be. (thickness is added to the inside of the ellipse). If set to 0, nothing will be drawn.
+        //! `opacity` sets the transparency of the ellipse.
+        //! `<= 0.0` means the ellipse will be completely transparent, while `>= 1.0` means the ellipse won't be transparent.
+
+        if !has_gaps(dashes) {
+            // outline without gaps is the solid outline
+            if thickness != 0 {
+                self.draw_ellipse(x, y, horizontal_axis, vertical_axis, color, thickness, opacity);
+            }
+        } else if opacity >= 0.0 && horizontal_axis > 0.0 && vertical_axis > 0.0 {
+            let paint: Paint<P> = color.into();
+            // center of the outline lies (thickness - 1) / 2 inside of the ellipse, so it covers the same pixels as the solid outline
+            let path: Path = Path::ellipse_outline(x, y, horizontal_axis, vertical_axis, (thickness as f64 - 1.0) / 2.0).dashed(dashes, dash_offset);
+            raster::stroke_path(self.width, self.height, &path, thickness, LineJoin::Miter, cap, &mut |x, y, coverage| self.paint_pixel(x, y, &paint, coverage * opacity));
+        }
+    }
 
-                                self.image_data[pix1_ind - 2 * (x_coord - x)][channel] = ((self.image_data[pix1_ind - 2 * (x_coord - x)][channel] as f64) * (pix2_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_rotated_ellipse(&mut self, x: f64, y: f64, horizontal_axis: f64, vertical_axis: f64, angle: f64, color: impl Into<Paint<P>>, thickness: usize, opacity: f64) {
+        //! Draws a new rotated ellipse. `x`, `y` are the coordinates of the center of the ellipse.
+        //! `horizontal_axis` defines the half length of the horizontal axis and `vertical_axis` the half length of the vertical axis of the ellipse before it is rotated.
+        //! `angle` (in radians) defines the counterclockwise rotation of the ellipse around its center.
+        //! `color` defines the color of the ellipse, which can also be a [Paint] like a [Gradient](crate::Gradient) (its alpha, if it has one, is multiplied with `opacity`).
+        //! `thickness` defines how thick the ellipse will be. (thickness is added to the inside of the ellipse). If set to 0, the ellipse will be filled.
+        //! `opacity` sets the transparency of the ellipse.
+        //! `<= 0.0` means the ellipse will be completely transparent, while `>= 1.0` means the ellipse won't be transparent.
 
-                                self.image_data[pix1_ind + 2 * (y_coord.ceil() as usize - y) * self.width][channel] = ((self.image_data[pix1_ind + 2 * (y_coord.ceil() as usize - y) * self.width][channel] as f64) * (pix2_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
+        if opacity >= 0.0 {
+            let paint: Paint<P> = color.into();
+            raster::ellipse(self.width, self.height, x, y, horizontal_axis, vertical_axis, angle, thickness, &mut |x, y, coverage| self.paint_pixel(x, y, &paint, coverage * opacity));
+        }
+    }
 
-                                self.image_data[pix1_ind + 2 * (y_coord.ceil() as usize - y) * self.width - 2 * (x_coord - x)][channel] = ((self.image_data[pix1_ind + 2 * (y_coord.ceil() as usize - y) * self.width - 2 * (x_coord - x)][channel] as f64) * (pix2_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-                            }
-                            if (y_coord.ceil() as usize) < previous_y {
-                                for pixel_ind in (pix1_ind - 2 * (x_coord - x) + 1)..pix1_ind {
-                                    for channel in 0..color.len() {
-                                        self.image_data[pixel_ind][channel] = ((self.image_data[pixel_ind][channel] as f64) * (1.0 - opacity) + (color[channel] as f64) * opacity).round() as u8;
-                                    }
-                                }
-                                for pixel_ind in (pix1_ind + 2 * (y_coord.ceil() as usize - y) * self.width - 2 * (x_coord - x) + 1)..(pix1_ind + 2 * (y_coord.ceil() as usize - y) * self.width) {
-                                    for channel in 0..color.len() {
-                                        self.image_data[pixel_ind][channel] = ((self.image_data[pixel_ind][channel] as f64) * (1.0 - opacity) + (color[channel] as f64) * opacity).round() as u8;
-                                    }
-                                }
-                                previous_y = y_coord.ceil() as usize
-                            }
-                        }
-                    }
-
-                    // for every y, calculate x and draw outer pixel, connecting inner pixels with solid line (for filling circle)
-                    for y_coord in y..(y_upperlimit + 1) {
-                        let x_coord: f64 = x0 + (radius_sqrd - (y_coord as f64 - y0).powi(2)).sqrt();
-
-                        if (x_coord - x_coord.round()).abs() < 0.00001 {
-                            // if point is very close to integer, just draw it on that pixel, and mirror to other 3 symmetric pixels
-                            if y_coord == y {
-                                for pixel_ind in (self.width * (self.height - 1 - y_coord) + (2 * x - x_coord.round() as usize))..(self.width * (self.height - 1 - y_coord) + x_coord.round() as usize + 1) {
-                                    for channel in 0..color.len() {
-                                        self.image_data[pixel_ind][channel] = ((self.image_data[pixel_ind][channel] as f64) * (1.0 - opacity) + (color[channel] as f64) * opacity).round() as u8;
-                                    }
-                                }
-                            } else {
-                                for pixel_ind in (self.width * (self.height - 1 - y_coord) + (2 * x - x_coord.round() as usize))..(self.width * (self.height - 1 - y_coord) + x_coord.round() as usize + 1) {
-                                    for channel in 0..color.len() {
-                                        self.image_data[pixel_ind][channel] = ((self.image_data[pixel_ind][channel] as f64) * (1.0 - opacity) + (color[channel] as f64) * opacity).round() as u8;
-                                    }
-                                }
-                                for pixel_ind in (self.width * (self.height - 1 - (2 * y - y_coord)) + (2 * x - x_coord.round() as usize))..(self.width * (self.height - 1 - (2 * y - y_coord)) + x_coord.round() as usize + 1) {
-                                    for channel in 0..color.len() {
-                                        self.image_data[pixel_ind][channel] = ((self.image_data[pixel_ind][channel] as f64) * (1.0 - opacity) + (color[channel] as f64) * opacity).round() as u8;
-                                    }
-                                }
-                            }
-                        } else {
-                            // split point between two pixels, and mirror to other 3 symmetric pixels
-                            let pix1_percentage: f64 = (x_coord - x_coord.floor()) * opacity;
-                            let pix2_percentage: f64 = 1.0 - pix1_percentage;
-                            let pix_ind: usize = self.width * (self.height - 1 - y_coord) + x_coord.ceil() as usize;
-                            for channel in 0..color.len() {
-                                // background color aware ===> color = color + (new_color - color) * color_percentage ===> color = color * (1 - color_percentage) + new_color * color_percentage
-                                self.image_data[pix_ind][channel] = ((self.image_data[pix_ind][channel] as f64) * (pix2_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-
-                                let mut temp_ind = pix_ind - 2 * (x_coord.ceil() as usize - x);
-                                self.image_data[temp_ind][channel] = ((self.image_data[temp_ind][channel] as f64) * (pix2_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-
-                                temp_ind = pix_ind + 2 * (y_coord - y) * self.width;
-                                self.image_data[temp_ind][channel] = ((self.image_data[temp_ind][channel] as f64) * (pix2_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-
-                                temp_ind -= 2 * (x_coord.ceil() as usize - x);
-                                self.image_data[temp_ind][channel] = ((self.image_data[temp_ind][channel] as f64) * (pix2_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-                            }
-                            for pixel_ind in (pix_ind - 2 * (x_coord.ceil() as usize - x) + 1)..pix_ind {
-                                for channel in 0..color.len() {
-                                    self.image_data[pixel_ind][channel] = ((self.image_data[pixel_ind][channel] as f64) * (1.0 - opacity) + (color[channel] as f64) * opacity).round() as u8;
-                                }
-                            }
-                            for pixel_ind in (pix_ind + 2 * (y_coord - y) * self.width - 2 * (x_coord.ceil() as usize - x) + 1)..(pix_ind + 2 * (y_coord - y) * self.width) {
-                                for channel in 0..color.len() {
-                                    self.image_data[pixel_ind][channel] = ((self.image_data[pixel_ind][channel] as f64) * (1.0 - opacity) + (color[channel] as f64) * opacity).round() as u8;
-                                }
-                            }
-                        }
-                    }
-                }
-            } else {
-                if opacity >= 1.0 {
-                    // DRAW SOLID CIRCLE
-
-                    // for every x, calculate y and split between top and bottom pixel
-                    for x_coord in x..(x_upperlimit + 1) {
-                        let y_coord: f64 = y0 + (radius_sqrd - (x_coord as f64 - x0).powi(2)).sqrt();
-
-                        if x_coord == x_upperlimit {
-                            y_upperlimit = y_coord.floor() as usize;
-                        }
-
-                        if (y_coord - y_coord.round()).abs() < 0.00001 {
-                            // if point is very close to integer, just draw it on that pixel, and mirror to other 3 symmetric pixels
-                            self.image_data[self.width * (self.height - 1 - (y_coord.round() as usize)) + x_coord] = color;
-                            self.image_data[self.width * (self.height - 1 - (2 * y - (y_coord.round() as usize))) + x_coord] = color;
-                            if x_coord != x {
-                                // new x coord (mirrored to left) ===> x_coord = x - (x_coord - x)
-                                self.image_data[self.width * (self.height - 1 - (y_coord.round() as usize)) + (2 * x - x_coord)] = color;
-                                self.image_data[self.width * (self.height - 1 - (2 * y - (y_coord.round() as usize))) + (2 * x - x_coord)] = color;
-                            }
-                        } else {
-                            // split point between two pixels, and mirror to other 3 symmetric pixels
-                            let pix1_percentage: f64 = y_coord - y_coord.floor();
-                            let pix2_percentage: f64 = 1.0 - pix1_percentage;
-                            let pix1_ind: usize = self.width * (self.height - 1 - (y_coord.ceil() as usize)) + x_coord;
-                            let pix2_ind: usize = pix1_ind + self.width;
-                            for channel in 0..color.len() {
-                                // background color aware ===> color = color + (new_color - color) * color_percentage ===> color = color * (1 - color_percentage) + new_color * color_percentage
-                                self.image_data[pix1_ind][channel] = ((self.image_data[pix1_ind][channel] as f64) * (pix2_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-                                self.image_data[pix2_ind][channel] = ((self.image_data[pix2_ind][channel] as f64) * (pix1_percentage) + (color[channel] as f64) * pix2_percentage).round() as u8;
-
-                                self.image_data[pix1_ind - 2 * (x_coord - x)][channel] = ((self.image_data[pix1_ind - 2 * (x_coord - x)][channel] as f64) * (pix2_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-                                self.image_data[pix2_ind - 2 * (x_coord - x)][channel] = ((self.image_data[pix2_ind - 2 * (x_coord - x)][channel] as f64) * (pix1_percentage) + (color[channel] as f64) * pix2_percentage).round() as u8;
-
-                                self.image_data[pix1_ind + 2 * (y_coord.ceil() as usize - y) * self.width][channel] = ((self.image_data[pix1_ind + 2 * (y_coord.ceil() as usize - y) * self.width][channel] as f64) * (pix2_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-                                self.image_data[pix2_ind + 2 * (y_coord.floor() as usize - y) * self.width][channel] = ((self.image_data[pix2_ind + 2 * (y_coord.floor() as usize - y) * self.width][channel] as f64) * (pix1_percentage) + (color[channel] as f64) * pix2_percentage).round() as u8;
-
-                                self.image_data[pix1_ind + 2 * (y_coord.ceil() as usize - y) * self.width - 2 * (x_coord - x)][channel] = ((self.image_data[pix1_ind + 2 * (y_coord.ceil() as usize - y) * self.width - 2 * (x_coord - x)][channel] as f64) * (pix2_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-                                self.image_data[pix2_ind + 2 * (y_coord.floor() as usize - y) * self.width - 2 * (x_coord - x)][channel] = ((self.image_data[pix2_ind + 2 * (y_coord.floor() as usize - y) * self.width - 2 * (x_coord - x)][channel] as f64) * (pix1_percentage) + (color[channel] as f64) * pix2_percentage).round() as u8;
+    pub fn draw_polygon(&mut self, points: &[(f64, f64)], fill_rule: FillRule, color: impl Into<Paint<P>>, thickness: usize, opacity: f64) {
+        //! Draws a new polygon. `points` are the coordinates of the vertices (the last vertex is connected to the first one).
+        //! `fill_rule` decides which parts of a polygon with self-intersecting edges are inside of it (see [FillRule]).
+        //! `color` defines the color of the polygon, which can also be a [Paint] like a [Gradient](crate::Gradient) (its alpha, if it has one, is multiplied with `opacity`).
+        //! `thickness` defines how thick the polygon will be. (thickness is added to the inside of the polygon). If set to 0, the polygon will be filled.
+        //! Like the other shapes, the polygon includes the pixels on its edges (its edges are moved half a pixel outwards),
+        //! so translucent polygons sharing an edge overlap on its pixels. Use [Image::fill_path()] to fill the exact area of adjacent polygons.
+        //! `opacity` sets the transparency of the polygon.
+        //! `<= 0.0` means the polygon will be completely transparent, while `>= 1.0` means the polygon won't be transparent.
 
-                            }
-                        }
-                    }
-
-                    // for every y, calculate x and split between left and right pixel (up to y_upperlimit - 1 as it is a special case handled separately)
-                    for y_coord in y..y_upperlimit {
-                        let x_coord: f64 = x0 + (radius_sqrd - (y_coord as f64 - y0).powi(2)).sqrt();
-
-                        if (x_coord - x_coord.round()).abs() < 0.00001 {
-                            // if point is very close to integer, just draw it on that pixel, and mirror to other 3 symmetric pixels
-                            self.image_data[self.width * (self.height - 1 - y_coord) + x_coord.round() as usize] = color;
-                            self.image_data[self.width * (self.height - 1 - y_coord) + (2 * x - x_coord.round() as usize)] = color;
-                            if y_coord != y {
-                                // new x coord (mirrored to left) ===> x_coord = x - (x_coord - x)
-                                self.image_data[self.width * (self.height - 1 - (2 * y - y_coord)) + x_coord.round() as usize] = color;
-                                self.image_data[self.width * (self.height - 1 - (2 * y - y_coord)) + (2 * x - x_coord.round() as usize)] = color;
-                            }
-                        } else {
-                            // split point between two pixels, and mirror to other 3 symmetric pixels
-                            let pix1_percentage: f64 = x_coord - x_coord.floor();
-                            let pix2_percentage: f64 = 1.0 - pix1_percentage;
-                            let pix_ind: usize = self.width * (self.height - 1 - y_coord) + x_coord.ceil() as usize;
-                            for channel in 0..color.len() {
-                                // background color aware ===> color = color + (new_color - color) * color_percentage ===> color = color * (1 - color_percentage) + new_color * color_percentage
-                                self.image_data[pix_ind][channel] = ((self.image_data[pix_ind][channel] as f64) * (pix2_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-                                self.image_data[pix_ind - 1][channel] = ((self.image_data[pix_ind - 1][channel] as f64) * (pix1_percentage) + (color[channel] as f64) * pix2_percentage).round() as u8;
-
-                                let mut temp_ind = pix_ind - 2 * (x_coord.ceil() as usize - x);
-                                self.image_data[temp_ind][channel] = ((self.image_data[temp_ind][channel] as f64) * (pix2_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-                                self.image_data[temp_ind + 1][channel] = ((self.image_data[temp_ind + 1][channel] as f64) * (pix1_percentage) + (color[channel] as f64) * pix2_percentage).round() as u8;
-
-                                temp_ind = pix_ind + 2 * (y_coord - y) * self.width;
-                                self.image_data[temp_ind][channel] = ((self.image_data[temp_ind][channel] as f64) * (pix2_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-                                self.image_data[temp_ind - 1][channel] = ((self.image_data[temp_ind - 1][channel] as f64) * (pix1_percentage) + (color[channel] as f64) * pix2_percentage).round() as u8;
-
-                                temp_ind -= 2 * (x_coord.ceil() as usize - x);
-                                self.image_data[temp_ind][channel] = ((self.image_data[temp_ind][channel] as f64) * (pix2_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-                                self.image_data[temp_ind + 1][channel] = ((self.image_data[temp_ind + 1][channel] as f64) * (pix1_percentage) + (color[channel] as f64) * pix2_percentage).round() as u8;
+        if opacity >= 0.0 {
+            let paint: Paint<P> = color.into();
+            raster::polygon(self.width, self.height, points, fill_rule, thickness, &mut |x, y, coverage| self.paint_pixel(x, y, &paint, coverage * opacity));
+        }
+    }
 
-                            }
-                        }
-                    }
-
-                    // special case when y = y_upperlimit, draw only outer pixel (as inner was already filled with something in x loop)
-                    let x_coord: f64 = x0 + (radius_sqrd - (y_upperlimit as f64 - y0).powi(2)).sqrt();
-                    let pix1_percentage: f64 = x_coord - x_coord.floor();
-                    let pix2_percentage: f64 = 1.0 - pix1_percentage;
-                    let pix_ind: usize = self.width * (self.height - 1 - y_upperlimit) + x_coord.ceil() as usize;
-                    for channel in 0..color.len() {
-                        // background color aware ===> color = color + (new_color - color) * color_percentage ===> color = color * (1 - color_percentage) + new_color * color_percentage
-                        self.image_data[pix_ind][channel] = ((self.image_data[pix_ind][channel] as f64) * (pix2_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-
-                        let mut temp_ind = pix_ind - 2 * (x_coord.ceil() as usize - x);
-                        self.image_data[temp_ind][channel] = ((self.image_data[temp_ind][channel] as f64) * (pix2_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-
-                        temp_ind = pix_ind + 2 * (y_upperlimit - y) * self.width;
-                        self.image_data[temp_ind][channel] = ((self.image_data[temp_ind][channel] as f64) * (pix2_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-
-                        temp_ind -= 2 * (x_coord.ceil() as usize - x);
-                        self.image_data[temp_ind][channel] = ((self.image_data[temp_ind][channel] as f64) * (pix2_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-                    }
-
-                } else {
-                    // DRAW TRANSPARENT CIRCLE
-
-                    // for every x, calculate y and split between top and bottom pixel
-                    for x_coord in x..(x_upperlimit + 1) {
-                        let y_coord: f64 = y0 + (radius_sqrd - (x_coord as f64 - x0).powi(2)).sqrt();
-
-                        if x_coord == x_upperlimit {
-                            y_upperlimit = y_coord.floor() as usize;
-                        }
-
-                        if (y_coord - y_coord.round()).abs() < 0.00001 {
-                            // if point is very close to integer, just draw it on that pixel, and mirror to other 3 symmetric pixels
-                            for channel in 0..color.len() {
-                                self.image_data[self.width * (self.height - 1 - (y_coord.round() as usize)) + x_coord][channel] = ((self.image_data[self.width * (self.height - 1 - (y_coord.round() as usize)) + x_coord][channel] as f64) * (1.0 - opacity) + (color[channel] as f64) * opacity).round() as u8;
-                                self.image_data[self.width * (self.height - 1 - (2 * y - (y_coord.round() as usize))) + x_coord][channel] = ((self.image_data[self.width * (self.height - 1 - (2 * y - (y_coord.round() as usize))) + x_coord][channel] as f64) * (1.0 - opacity) + (color[channel] as f64) * opacity).round() as u8;
-                            }
-                            if x_coord != x {
-                                // new x coord (mirrored to left) ===> x_coord = x - (x_coord - x)
-                                for channel in 0..color.len() {
-                                    self.image_data[self.width * (self.height - 1 - (y_coord.round() as usize)) + (2 * x - x_coord)][channel] = ((self.image_data[self.width * (self.height - 1 - (y_coord.round() as usize)) + (2 * x - x_coord)][channel] as f64) * (1.0 - opacity) + (color[channel] as f64) * opacity).round() as u8;
-                                    self.image_data[self.width * (self.height - 1 - (2 * y - (y_coord.round() as usize))) + (2 * x - x_coord)][channel] = ((self.image_data[self.width * (self.height - 1 - (2 * y - (y_coord.round() as usize))) + (2 * x - x_coord)][channel] as f64) * (1.0 - opacity) + (color[channel] as f64) * opacity).round() as u8;
-                                }
-                            }
-                        } else {
-                            // split point between two pixels, and mirror to other 3 symmetric pixels
-                            let pix1_percentage: f64 = (y_coord - y_coord.floor()) * opacity;
-                            let pix2_percentage: f64 = (y_coord.ceil() - y_coord) * opacity;
-                            let pix1_ind: usize = self.width * (self.height - 1 - (y_coord.ceil() as usize)) + x_coord;
-                            let pix2_ind: usize = pix1_ind + self.width;
-                            for channel in 0..color.len() {
-                                // background color aware ===> color = color + (new_color - color) * color_percentage ===> color = color * (1 - color_percentage) + new_color * color_percentage
-                                self.image_data[pix1_ind][channel] = ((self.image_data[pix1_ind][channel] as f64) * (1.0 - pix1_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-                                self.image_data[pix2_ind][channel] = ((self.image_data[pix2_ind][channel] as f64) * (1.0 - pix2_percentage) + (color[channel] as f64) * pix2_percentage).round() as u8;
-
-                                self.image_data[pix1_ind - 2 * (x_coord - x)][channel] = ((self.image_data[pix1_ind - 2 * (x_coord - x)][channel] as f64) * (1.0 - pix1_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-                                self.image_data[pix2_ind - 2 * (x_coord - x)][channel] = ((self.image_data[pix2_ind - 2 * (x_coord - x)][channel] as f64) * (1.0 - pix2_percentage) + (color[channel] as f64) * pix2_percentage).round() as u8;
-
-                                self.image_data[pix1_ind + 2 * (y_coord.ceil() as usize - y) * self.width][channel] = ((self.image_data[pix1_ind + 2 * (y_coord.ceil() as usize - y) * self.width][channel] as f64) * (1.0 - pix1_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-                                self.image_data[pix2_ind + 2 * (y_coord.floor() as usize - y) * self.width][channel] = ((self.image_data[pix2_ind + 2 * (y_coord.floor() as usize - y) * self.width][channel] as f64) * (1.0 - pix2_percentage) + (color[channel] as f64) * pix2_percentage).round() as u8;
-
-                                self.image_data[pix1_ind + 2 * (y_coord.ceil() as usize - y) * self.width - 2 * (x_coord - x)][channel] = ((self.image_data[pix1_ind + 2 * (y_coord.ceil() as usize - y) * self.width - 2 * (x_coord - x)][channel] as f64) * (1.0 - pix1_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-                                self.image_data[pix2_ind + 2 * (y_coord.floor() as usize - y) * self.width - 2 * (x_coord - x)][channel] = ((self.image_data[pix2_ind + 2 * (y_coord.floor() as usize - y) * self.width - 2 * (x_coord - x)][channel] as f64) * (1.0 - pix2_percentage) + (color[channel] as f64) * pix2_percentage).round() as u8;
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_triangle(&mut self, x1: f64, y1: f64, x2: f64, y2: f64, x3: f64, y3: f64, color: impl Into<Paint<P>>, thickness: usize, opacity: f64) {
+        //! Draws a new triangle. `x1`, `y1`, `x2`, `y2` and `x3`, `y3` are the coordinates of its vertices.
+        //! `color` defines the color of the triangle, which can also be a [Paint] like a [Gradient](crate::Gradient) (its alpha, if it has one, is multiplied with `opacity`).
+        //! `thickness` defines how thick the triangle will be. (thickness is added to the inside of the triangle). If set to 0, the triangle will be filled.
+        //! `opacity` sets the transparency of the triangle.
+        //! `<= 0.0` means the triangle will be completely transparent, while `>= 1.0` means the triangle won't be transparent.
 
-                            }
-                        }
-                    }
-
-                    // for every y, calculate x and split between left and right pixel (up to y_upperlimit - 1 as it is a special case handled separately)
-                    for y_coord in y..y_upperlimit {
-                        let x_coord: f64 = x0 + (radius_sqrd - (y_coord as f64 - y0).powi(2)).sqrt();
-
-                        if (x_coord - x_coord.round()).abs() < 0.00001 {
-                            // if point is very close to integer, just draw it on that pixel, and mirror to other 3 symmetric pixels
-                            for channel in 0..color.len() {
-                                self.image_data[self.width * (self.height - 1 - y_coord) + x_coord.round() as usize][channel] = ((self.image_data[self.width * (self.height - 1 - y_coord) + x_coord.round() as usize][channel] as f64) * (1.0 - opacity) + (color[channel] as f64) * opacity).round() as u8;
-                                self.image_data[self.width * (self.height - 1 - y_coord) + (2 * x - x_coord.round() as usize)][channel] = ((self.image_data[self.width * (self.height - 1 - y_coord) + (2 * x - x_coord.round() as usize)][channel] as f64) * (1.0 - opacity) + (color[channel] as f64) * opacity).round() as u8;
-                            }
-                            if y_coord != y {
-                                // new x coord (mirrored to left) ===> x_coord = x - (x_coord - x)
-                                for channel in 0..color.len() {
-                                    self.image_data[self.width * (self.height - 1 - (2 * y - y_coord)) + x_coord.round() as usize][channel] = ((self.image_data[self.width * (self.height - 1 - (2 * y - y_coord)) + x_coord.round() as usize][channel] as f64) * (1.0 - opacity) + (color[channel] as f64) * opacity).round() as u8;
-                                    self.image_data[self.width * (self.height - 1 - (2 * y - y_coord)) + (2 * x - x_coord.round() as usize)][channel] = ((self.image_data[self.width * (self.height - 1 - (2 * y - y_coord)) + (2 * x - x_coord.round() as usize)][channel] as f64) * (1.0 - opacity) + (color[channel] as f64) * opacity).round() as u8;
-                                }
-                            }
-                        } else {
-                            // split point between two pixels, and mirror to other 3 symmetric pixels
-                            let pix1_percentage: f64 = (x_coord - x_coord.floor()) * opacity;
-                            let pix2_percentage: f64 = (x_coord.ceil() - x_coord) * opacity;
-                            let pix_ind: usize = self.width * (self.height - 1 - y_coord) + x_coord.ceil() as usize;
-                            for channel in 0..color.len() {
-                                // background color aware ===> color = color + (new_color - color) * color_percentage ===> color = color * (1 - color_percentage) + new_color * color_percentage
-                                self.image_data[pix_ind][channel] = ((self.image_data[pix_ind][channel] as f64) * (1.0 - pix1_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-                                self.image_data[pix_ind - 1][channel] = ((self.image_data[pix_ind - 1][channel] as f64) * (1.0 - pix2_percentage) + (color[channel] as f64) * pix2_percentage).round() as u8;
-
-                                let mut temp_ind = pix_ind - 2 * (x_coord.ceil() as usize - x);
-                                self.image_data[temp_ind][channel] = ((self.image_data[temp_ind][channel] as f64) * (1.0 - pix1_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-                                self.image_data[temp_ind + 1][channel] = ((self.image_data[temp_ind + 1][channel] as f64) * (1.0 - pix2_percentage) + (color[channel] as f64) * pix2_percentage).round() as u8;
-
-                                temp_ind = pix_ind + 2 * (y_coord - y) * self.width;
-                                self.image_data[temp_ind][channel] = ((self.image_data[temp_ind][channel] as f64) * (1.0 - pix1_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-                                self.image_data[temp_ind - 1][channel] = ((self.image_data[temp_ind - 1][channel] as f64) * (1.0 - pix2_percentage) + (color[channel] as f64) * pix2_percentage).round() as u8;
-
-                                temp_ind -= 2 * (x_coord.ceil() as usize - x);
-                                self.image_data[temp_ind][channel] = ((self.image_data[temp_ind][channel] as f64) * (1.0 - pix1_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-                                self.image_data[temp_ind + 1][channel] = ((self.image_data[temp_ind + 1][channel] as f64) * (1.0 - pix2_percentage) + (color[channel] as f64) * pix2_percentage).round() as u8;
+        if opacity >= 0.0 {
+            let paint: Paint<P> = color.into();
+            raster::polygon(self.width, self.height, &[(x1, y1), (x2, y2), (x3, y3)], FillRule::NonZero, thickness, &mut |x, y, coverage| self.paint_pixel(x, y, &paint, coverage * opacity));
+        }
+    }
 
-                            }
-                        }
-                    }
-
-                    // special case when y = y_upperlimit, draw only outer pixel (as inner was already filled with something in x loop)
-                    let x_coord: f64 = x0 + (radius_sqrd - (y_upperlimit as f64 - y0).powi(2)).sqrt();
-                    let pix1_percentage: f64 = (x_coord - x_coord.floor()) * opacity;
-                    let pix_ind: usize = self.width * (self.height - 1 - y_upperlimit) + x_coord.ceil() as usize;
-                    for channel in 0..color.len() {
-                        // background color aware ===> color = color + (new_color - color) * color_percentage ===> color = color * (1 - color_percentage) + new_color * color_percentage
-                        self.image_data[pix_ind][channel] = ((self.image_data[pix_ind][channel] as f64) * (1.0 - pix1_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-
-                        let mut temp_ind = pix_ind - 2 * (x_coord.ceil() as usize - x);
-                        self.image_data[temp_ind][channel] = ((self.image_data[temp_ind][channel] as f64) * (1.0 - pix1_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-
-                        temp_ind = pix_ind + 2 * (y_upperlimit - y) * self.width;
-                        self.image_data[temp_ind][channel] = ((self.image_data[temp_ind][channel] as f64) * (1.0 - pix1_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-
-                        temp_ind -= 2 * (x_coord.ceil() as usize - x);
-                        self.image_data[temp_ind][channel] = ((self.image_data[temp_ind][channel] as f64) * (1.0 - pix1_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-                    }
-                }
-            }
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_regular_polygon(&mut self, x: f64, y: f64, radius: f64, sides: usize, rotation: f64, color: impl Into<Paint<P>>, thickness: usize, opacity: f64) {
+        //! Draws a new regular polygon (all sides and angles are equal). `x`, `y` are the coordinates of the center of the polygon.
+        //! `radius` defines the distance of the vertices from the center.
+        //! `sides` defines the number of sides (at least 3, otherwise nothing will be drawn).
+        //! `rotation` (in radians) defines the counterclockwise rotation of the polygon, with `0.0` one of the vertices points straight up.
+        //! `color` defines the color of the polygon, which can also be a [Paint] like a [Gradient](crate::Gradient) (its alpha, if it has one, is multiplied with `opacity`).
+        //! `thickness` defines how thick the polygon will be. (thickness is added to the inside of the polygon). If set to 0, the polygon will be filled.
+        //! `opacity` sets the transparency of the polygon.
+        //! `<= 0.0` means the polygon will be completely transparent, while `>= 1.0` means the polygon won't be transparent.
+
+        if opacity >= 0.0 {
+            let paint: Paint<P> = color.into();
+            raster::regular_polygon(self.width, self.height, x, y, radius, sides, rotation, thickness, &mut |x, y, coverage| self.paint_pixel(x, y, &paint, coverage * opacity));
         }
     }
 
     #[allow(clippy::too_many_arguments)]
-    #[allow(clippy::collapsible_else_if)]
-    pub fn draw_ellipse(&mut self, x: usize, y: usize, horizontal_axis: usize, vertical_axis: usize, color: [u8; 3], thickness: usize, opacity: f64) {
-        //! Draws a new ellipse. `x`, `y` are the coordinates of the center of the ellipse.
-        //! `horizontal_axis` defines the half length of the horizontal axis.
-        //! `vertical_axis` defines the half length of the vertical axis.
-        //! `color` defines the color of the ellipse.
-        //! `thickness` defines how thick the ellipse will be. (currently doesn't do anything). If set to 0, the ellipse will be filled.
-        //! `opacity` sets the transparency of the ellipse.
-        //! `<= 0.0` means the ellipse will be completely transparent, while `>= 1.0` means the ellipse won't be transparent.
+    pub fn draw_star(&mut self, x: f64, y: f64, outer_radius: f64, inner_radius: f64, points: usize, color: impl Into<Paint<P>>, thickness: usize, opacity: f64) {
+        //! Draws a new star. `x`, `y` are the coordinates of the center of the star.
+        //! `outer_radius` defines the distance of the tips from the center, and `inner_radius` the distance of the vertices between the tips from the center.
+        //! `points` defines the number of tips (at least 2, otherwise nothing will be drawn), one of the tips points straight up.
+        //! `color` defines the color of the star, which can also be a [Paint] like a [Gradient](crate::Gradient) (its alpha, if it has one, is multiplied with `opacity`).
+        //! `thickness` defines how thick the star will be. (thickness is added to the inside of the star). If set to 0, the star will be filled.
+        //! `opacity` sets the transparency of the star.
+        //! `<= 0.0` means the star will be completely transparent, while `>= 1.0` means the star won't be transparent.
 
-        if (horizontal_axis > 0) && (vertical_axis > 0) && (opacity >= 0.0) && (x < self.width) && (y < self.height) && (x >= horizontal_axis) && (y >= vertical_axis) && (x + horizontal_axis < self.width) && (y + vertical_axis < self.height) {
-            let x0: f64 = x as f64;
-            let y0: f64 = y as f64;
-            let x_upperlimit: usize = x + ((horizontal_axis.pow(2) as f64) / ((horizontal_axis.pow(2) + vertical_axis.pow(2)) as f64).sqrt()).round() as usize;  // x up to which draw adjacent pixels vertically
-            let mut y_upperlimit: usize = 0;  // y up to which draw adjacent pixels horizontally (is initialized to 0, but is changed later)
-
-            if thickness == 0 {
-                if opacity >= 1.0 {
-                    // DRAW FILLED SOLID ELLIPSE
-
-                    // for every x, calculate y and draw outer pixel, connecting inner pixels with solid line (for filling circle)
-                    let mut previous_y: usize = y + vertical_axis;
-                    for x_coord in x..(x_upperlimit + 1) {
-                        let y_coord: f64 = y0 + (vertical_axis as f64) * (1.0 - ((x_coord as f64 - x0) / horizontal_axis as f64).powi(2)).sqrt();
-
-                        if x_coord == x_upperlimit {
-                            y_upperlimit = y_coord.floor() as usize;
-                        }
-
-                        if (y_coord - y_coord.round()).abs() < 0.00001 {
-                            // if point is very close to integer, just draw it on that pixel, and mirror to other 3 symmetric pixels
-                            if x_coord == x {
-                                self.image_data[self.width * (self.height - 1 - (y_coord.round() as usize)) + x_coord] = color;
-                                self.image_data[self.width * (self.height - 1 - (2 * y - (y_coord.round() as usize))) + x_coord] = color;
-                            } else if y_coord.round() as usize > y_upperlimit {
-                                self.image_data[(self.width * (self.height - 1 - (y_coord.round() as usize)) + (2 * x - x_coord))..(self.width * (self.height - 1 - (y_coord.round() as usize)) + x_coord + 1)].fill(color);
-                                self.image_data[(self.width * (self.height - 1 - (2 * y - (y_coord.round() as usize))) + (2 * x - x_coord))..(self.width * (self.height - 1 - (2 * y - (y_coord.round() as usize))) + x_coord + 1)].fill(color);
-                                previous_y = y_coord.round() as usize;
-                            }
-                        } else {
-                            // split point between two pixels, and mirror to other 3 symmetric pixels
-                            let pix1_percentage: f64 = y_coord - y_coord.floor();
-                            let pix2_percentage: f64 = 1.0 - pix1_percentage;
-                            let pix1_ind: usize = self.width * (self.height - 1 - (y_coord.ceil() as usize)) + x_coord;
-                            for channel in 0..color.len() {
-                                // background color aware ===> color = color + (new_color - color) * color_percentage ===> color = color * (1 - color_percentage) + new_color * color_percentage
-                                self.image_data[pix1_ind][channel] = ((self.image_data[pix1_ind][channel] as f64) * (pix2_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
+        if opacity >= 0.0 {
+            let paint: Paint<P> = color.into();
+            raster::star(self.width, self.height, x, y, outer_radius, inner_radius, points, thickness, &mut |x, y, coverage| self.paint_pixel(x, y, &paint, coverage * opacity));
+        }
+    }
 
-                                self.image_data[pix1_ind - 2 * (x_coord - x)][channel] = ((self.image_data[pix1_ind - 2 * (x_coord - x)][channel] as f64) * (pix2_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_polyline(&mut self, points: &[(f64, f64)], join: LineJoin, cap: LineCap, color: impl Into<Paint<P>>, thickness: usize, opacity: f64) {
+        //! Draws new connected lines. `points` are the coordinates of the points that are connected one after another.
+        //! `join` defines the shape of the joints between the lines (see [LineJoin]).
+        //! `cap` defines the shape of the first and the last point (see [LineCap]).
+        //! `color` defines the color of the lines, which can also be a [Paint] like a [Gradient](crate::Gradient) (its alpha, if it has one, is multiplied with `opacity`).
+        //! `thickness` defines how thick the lines will be (measured perpendicular to the lines, centered on them). If set to 0, nothing will be drawn.
+        //! `opacity` sets the transparency of the lines. `<= 0.0` means the lines will be completely transparent, while `>= 1.0` means the lines won't be transparent.
+        //! Every pixel is blended only once, so overlapping parts of translucent lines are not darker.
 
-                                self.image_data[pix1_ind + 2 * (y_coord.ceil() as usize - y) * self.width][channel] = ((self.image_data[pix1_ind + 2 * (y_coord.ceil() as usize - y) * self.width][channel] as f64) * (pix2_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
+        if opacity >= 0.0 {
+            let paint: Paint<P> = color.into();
+            raster::stroke_path(self.width, self.height, &Path::polyline(points), thickness, join, cap, &mut |x, y, coverage| self.paint_pixel(x, y, &paint, coverage * opacity));
+        }
+    }
 
-                                self.image_data[pix1_ind + 2 * (y_coord.ceil() as usize - y) * self.width - 2 * (x_coord - x)][channel] = ((self.image_data[pix1_ind + 2 * (y_coord.ceil() as usize - y) * self.width - 2 * (x_coord - x)][channel] as f64) * (pix2_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-                            }
-                            if (y_coord.ceil() as usize) < previous_y {
-                                self.image_data[(pix1_ind - 2 * (x_coord - x) + 1)..pix1_ind].fill(color);
-                                self.image_data[(pix1_ind + 2 * (y_coord.ceil() as usize - y) * self.width - 2 * (x_coord - x) + 1)..(pix1_ind + 2 * (y_coord.ceil() as usize - y) * self.width)].fill(color);
-                                previous_y = y_coord.ceil() as usize
-                            }
-                        }
-                    }
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_dashed_polyline(&mut self, points: &[(f64, f64)], dashes: &[f64], dash_offset: f64, join: LineJoin, cap: LineCap, color: impl Into<Paint<P>>, thickness: usize, opacity: f64) {
+        //! Draws new dashed connected lines. `points` are the coordinates of the points that are connected one after another.
+        //! `dashes` are the lengths (in pixels) of the dashes and the gaps between them, starting from the first point and continuing around the joints,
+        //! and `dash_offset` defines how far into the pattern the lines start (see [Path::dashed()]).
+        //! `join` defines the shape of the joints inside of the dashes (see [LineJoin]).
+        //! `cap` defines the shape of the ends of the dashes (see [LineCap]).
+        //! `color` defines the color of the lines, which can also be a [Paint] like a [Gradient](crate::Gradient) (its alpha, if it has one, is multiplied with `opacity`).
+        //! `thickness` defines how thick the lines will be (measured perpendicular to the lines, centered on them). If set to 0, nothing will be drawn.
+        //! `opacity` sets the transparency of the lines. `<= 0.0` means the lines will be completely transparent, while `>= 1.0` means the lines won't be transparent.
 
-                    // for every y, calculate x and draw outer pixel, connecting inner pixels with solid line (for filling circle)
-                    for y_coord in y..(y_upperlimit + 1) {
-                        let x_coord: f64 = x0 + (horizontal_axis as f64) * (1.0 - ((y_coord as f64 - y0) / vertical_axis as f64).powi(2)).sqrt();
+        if opacity >= 0.0 {
+            let paint: Paint<P> = color.into();
+            raster::stroke_path(self.width, self.height, &Path::polyline(points).dashed(dashes, dash_offset), thickness, join, cap, &mut |x, y, coverage| self.paint_pixel(x, y, &paint, coverage * opacity));
+        }
+    }
 
-                        if (x_coord - x_coord.round()).abs() < 0.00001 {
-                            // if point is very close to integer, just draw it on that pixel, and mirror to other 3 symmetric pixels
-                            if y_coord == y {
-                                self.image_data[(self.width * (self.height - 1 - y_coord) + (2 * x - x_coord.round() as usize))..(self.width * (self.height - 1 - y_coord) + x_coord.round() as usize + 1)].fill(color);
-                            } else {
-                                self.image_data[(self.width * (self.height - 1 - y_coord) + (2 * x - x_coord.round() as usize))..(self.width * (self.height - 1 - y_coord) + (x_coord.round() as usize) + 1)].fill(color);
-                                self.image_data[(self.width * (self.height - 1 - (2 * y - y_coord)) + (2 * x - x_coord.round() as usize))..(self.width * (self.height - 1 - (2 * y - y_coord)) + (x_coord.round() as usize) + 1)].fill(color);
-                            }
-                        } else {
-                            // split point between two pixels, and mirror to other 3 symmetric pixels
-                            let pix1_percentage: f64 = x_coord - x_coord.floor();
-                            let pix2_percentage: f64 = 1.0 - pix1_percentage;
-                            let pix_ind: usize = self.width * (self.height - 1 - y_coord) + x_coord.ceil() as usize;
-                            for channel in 0..color.len() {
-                                // background color aware ===> color = color + (new_color - color) * color_percentage ===> color = color * (1 - color_percentage) + new_color * color_percentage
-                                self.image_data[pix_ind][channel] = ((self.image_data[pix_ind][channel] as f64) * (pix2_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-
-                                let mut temp_ind = pix_ind - 2 * (x_coord.ceil() as usize - x);
-                                self.image_data[temp_ind][channel] = ((self.image_data[temp_ind][channel] as f64) * (pix2_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-
-                                temp_ind = pix_ind + 2 * (y_coord - y) * self.width;
-                                self.image_data[temp_ind][channel] = ((self.image_data[temp_ind][channel] as f64) * (pix2_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-
-                                temp_ind -= 2 * (x_coord.ceil() as usize - x);
-                                self.image_data[temp_ind][channel] = ((self.image_data[temp_ind][channel] as f64) * (pix2_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-                            }
-                            self.image_data[(pix_ind - 2 * (x_coord.ceil() as usize - x) + 1)..pix_ind].fill(color);
-                            self.image_data[(pix_ind + 2 * (y_coord - y) * self.width - 2 * (x_coord.ceil() as usize - x) + 1)..(pix_ind + 2 * (y_coord - y) * self.width)].fill(color);
-                        }
-                    }
-
-                } else {
-                    // DRAW FILLED TRANSPARENT ELLIPSE
-
-                    // for every x, calculate y and draw outer pixel, connecting inner pixels with solid line (for filling circle)
-                    let mut previous_y: usize = y + vertical_axis;
-                    for x_coord in x..(x_upperlimit + 1) {
-                        let y_coord: f64 = y0 + (vertical_axis as f64) * (1.0 - ((x_coord as f64 - x0) / horizontal_axis as f64).powi(2)).sqrt();
-
-                        if x_coord == x_upperlimit {
-                            y_upperlimit = y_coord.floor() as usize;
-                        }
-
-                        if (y_coord - y_coord.round()).abs() < 0.00001 {
-                            // if point is very close to integer, just draw it on that pixel, and mirror to other 3 symmetric pixels
-                            if x_coord == x {
-                                for channel in 0..color.len() {
-                                    self.image_data[self.width * (self.height - 1 - y_coord.round() as usize) + x_coord][channel] = ((self.image_data[self.width * (self.height - 1 - y_coord.round() as usize) + x_coord][channel] as f64) * (1.0 - opacity) + (color[channel] as f64) * opacity).round() as u8;
-                                    self.image_data[self.width * (self.height - 1 - (2 * y - (y_coord.round() as usize))) + x_coord][channel] = ((self.image_data[self.width * (self.height - 1 - (2 * y - (y_coord.round() as usize))) + x_coord][channel] as f64) * (1.0 - opacity) + (color[channel] as f64) * opacity).round() as u8;
-                                }
-                            } else if y_coord.round() as usize > y_upperlimit {
-                                for pixel_ind in (self.width * (self.height - 1 - (y_coord.round() as usize)) + (2 * x - x_coord))..(self.width * (self.height - 1 - (y_coord.round() as usize)) + x_coord + 1) {
-                                    for channel in 0..color.len() {
-                                        self.image_data[pixel_ind][channel] = ((self.image_data[pixel_ind][channel] as f64) * (1.0 - opacity) + (color[channel] as f64) * opacity).round() as u8;
-                                    }
-                                }
-                                for pixel_ind in (self.width * (self.height - 1 - (2 * y - (y_coord.round() as usize))) + (2 * x - x_coord))..(self.width * (self.height - 1 - (2 * y - (y_coord.round() as usize))) + x_coord + 1) {
-                                    for channel in 0..color.len() {
-                                        self.image_data[pixel_ind][channel] = ((self.image_data[pixel_ind][channel] as f64) * (1.0 - opacity) + (color[channel] as f64) * opacity).round() as u8;
-                                    }
-                                }
-                                previous_y = y_coord.round() as usize;
-                            }
-                        } else {
-                            // split point between two pixels, and mirror to other 3 symmetric pixels
-                            let pix1_percentage: f64 = (y_coord - y_coord.floor()) * opacity;
-                            let pix2_percentage: f64 = 1.0 - pix1_percentage;
-                            let pix1_ind: usize = self.width * (self.height - 1 - (y_coord.ceil() as usize)) + x_coord;
-                            for channel in 0..color.len() {
-                                // background color aware ===> color = color + (new_color - color) * color_percentage ===> color = color * (1 - color_percentage) + new_color * color_percentage
-                                self.image_data[pix1_ind][channel] = ((self.image_data[pix1_ind][channel] as f64) * (pix2_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_quadratic_bezier(&mut self, start: (f64, f64), control: (f64, f64), end: (f64, f64), color: impl Into<Paint<P>>, thickness: usize, opacity: f64) {
+        //! Draws a new quadratic Bezier curve. `start` and `end` are the coordinates of the end points of the curve, `control` is the coordinate of its control point.
+        //! `color` defines the color of the curve, which can also be a [Paint] like a [Gradient](crate::Gradient) (its alpha, if it has one, is multiplied with `opacity`).
+        //! `thickness` defines how thick the curve will be (measured perpendicular to the curve, centered on it). If set to 0, nothing will be drawn.
+        //! `opacity` sets the transparency of the curve. `<= 0.0` means the curve will be completely transparent, while `>= 1.0` means the curve won't be transparent.
 
-                                self.image_data[pix1_ind - 2 * (x_coord - x)][channel] = ((self.image_data[pix1_ind - 2 * (x_coord - x)][channel] as f64) * (pix2_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
+        if opacity >= 0.0 {
+            let paint: Paint<P> = color.into();
+            let path: Path = Path::new().move_to(start.0, start.1).quad_to(control.0, control.1, end.0, end.1);
+            raster::stroke_path(self.width, self.height, &path, thickness, LineJoin::Miter, LineCap::Butt, &mut |x, y, coverage| self.paint_pixel(x, y, &paint, coverage * opacity));
+        }
+    }
 
-                                self.image_data[pix1_ind + 2 * (y_coord.ceil() as usize - y) * self.width][channel] = ((self.image_data[pix1_ind + 2 * (y_coord.ceil() as usize - y) * self.width][channel] as f64) * (pix2_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_cubic_bezier(&mut self, start: (f64, f64), control1: (f64, f64), control2: (f64, f64), end: (f64, f64), color: impl Into<Paint<P>>, thickness: usize, opacity: f64) {
+        //! Draws a new cubic Bezier curve. `start` and `end` are the coordinates of the end points of the curve, `control1` and `control2` are the coordinates of its control points.
+        //! `color` defines the color of the curve, which can also be a [Paint] like a [Gradient](crate::Gradient) (its alpha, if it has one, is multiplied with `opacity`).
+        //! `thickness` defines how thick the curve will be (measured perpendicular to the curve, centered on it). If set to 0, nothing will be drawn.
+        //! `opacity` sets the transparency of the curve. `<= 0.0` means the curve will be completely transparent, while `>= 1.0` means the curve won't be transparent.
 
-                                self.image_data[pix1_ind + 2 * (y_coord.ceil() as usize - y) * self.width - 2 * (x_coord - x)][channel] = ((self.image_data[pix1_ind + 2 * (y_coord.ceil() as usize - y) * self.width - 2 * (x_coord - x)][channel] as f64) * (pix2_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-                            }
-                            if (y_coord.ceil() as usize) < previous_y {
-                                for pixel_ind in (pix1_ind - 2 * (x_coord - x) + 1)..pix1_ind {
-                                    for channel in 0..color.len() {
-                                        self.image_data[pixel_ind][channel] = ((self.image_data[pixel_ind][channel] as f64) * (1.0 - opacity) + (color[channel] as f64) * opacity).round() as u8;
-                                    }
-                                }
-                                for pixel_ind in (pix1_ind + 2 * (y_coord.ceil() as usize - y) * self.width - 2 * (x_coord - x) + 1)..(pix1_ind + 2 * (y_coord.ceil() as usize - y) * self.width) {
-                                    for channel in 0..color.len() {
-                                        self.image_data[pixel_ind][channel] = ((self.image_data[pixel_ind][channel] as f64) * (1.0 - opacity) + (color[channel] as f64) * opacity).round() as u8;
-                                    }
-                                }
-                                previous_y = y_coord.ceil() as usize
-                            }
-                        }
-                    }
-
-                    // for every y, calculate x and draw outer pixel, connecting inner pixels with solid line (for filling circle)
-                    for y_coord in y..(y_upperlimit + 1) {
-                        let x_coord: f64 = x0 + (horizontal_axis as f64) * (1.0 - ((y_coord as f64 - y0) / vertical_axis as f64).powi(2)).sqrt();
-
-                        if (x_coord - x_coord.round()).abs() < 0.00001 {
-                            // if point is very close to integer, just draw it on that pixel, and mirror to other 3 symmetric pixels
-                            if x_coord.round() as usize == x {
-                                continue
-                            } else if y_coord == y {
-                                for pixel_ind in (self.width * (self.height - 1 - y_coord) + (2 * x - x_coord.round() as usize))..(self.width * (self.height - 1 - y_coord) + x_coord.round() as usize + 1) {
-                                    for channel in 0..color.len() {
-                                        self.image_data[pixel_ind][channel] = ((self.image_data[pixel_ind][channel] as f64) * (1.0 - opacity) + (color[channel] as f64) * opacity).round() as u8;
-                                    }
-                                }
-                            } else {
-                                for pixel_ind in (self.width * (self.height - 1 - y_coord) + (2 * x - x_coord.round() as usize))..(self.width * (self.height - 1 - y_coord) + x_coord.round() as usize + 1) {
-                                    for channel in 0..color.len() {
-                                        self.image_data[pixel_ind][channel] = ((self.image_data[pixel_ind][channel] as f64) * (1.0 - opacity) + (color[channel] as f64) * opacity).round() as u8;
-                                    }
-                                }
-                                for pixel_ind in (self.width * (self.height - 1 - (2 * y - y_coord)) + (2 * x - x_coord.round() as usize))..(self.width * (self.height - 1 - (2 * y - y_coord)) + x_coord.round() as usize + 1) {
-                                    for channel in 0..color.len() {
-                                        self.image_data[pixel_ind][channel] = ((self.image_data[pixel_ind][channel] as f64) * (1.0 - opacity) + (color[channel] as f64) * opacity).round() as u8;
-                                    }
-                                }
-                            }
-                        } else {
-                            // split point between two pixels, and mirror to other 3 symmetric pixels
-                            let pix1_percentage: f64 = (x_coord - x_coord.floor()) * opacity;
-                            let pix2_percentage: f64 = 1.0 - pix1_percentage;
-                            let pix_ind: usize = self.width * (self.height - 1 - y_coord) + x_coord.ceil() as usize;
-                            for channel in 0..color.len() {
-                                // background color aware ===> color = color + (new_color - color) * color_percentage ===> color = color * (1 - color_percentage) + new_color * color_percentage
-                                self.image_data[pix_ind][channel] = ((self.image_data[pix_ind][channel] as f64) * (pix2_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-
-                                let mut temp_ind = pix_ind - 2 * (x_coord.ceil() as usize - x);
-                                self.image_data[temp_ind][channel] = ((self.image_data[temp_ind][channel] as f64) * (pix2_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-
-                                temp_ind = pix_ind + 2 * (y_coord - y) * self.width;
-                                self.image_data[temp_ind][channel] = ((self.image_data[temp_ind][channel] as f64) * (pix2_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-
-                                temp_ind -= 2 * (x_coord.ceil() as usize - x);
-                                self.image_data[temp_ind][channel] = ((self.image_data[temp_ind][channel] as f64) * (pix2_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-                            }
-                            for pixel_ind in (pix_ind - 2 * (x_coord.ceil() as usize - x) + 1)..pix_ind {
-                                for channel in 0..color.len() {
-                                    self.image_data[pixel_ind][channel] = ((self.image_data[pixel_ind][channel] as f64) * (1.0 - opacity) + (color[channel] as f64) * opacity).round() as u8;
-                                }
-                            }
-                            for pixel_ind in (pix_ind + 2 * (y_coord - y) * self.width - 2 * (x_coord.ceil() as usize - x) + 1)..(pix_ind + 2 * (y_coord - y) * self.width) {
-                                for channel in 0..color.len() {
-                                    self.image_data[pixel_ind][channel] = ((self.image_data[pixel_ind][channel] as f64) * (1.0 - opacity) + (color[channel] as f64) * opacity).round() as u8;
-                                }
-                            }
-                        }
-                    }
-                }
-            } else {
-                if opacity >= 1.0 {
-                    // DRAW SOLID ELLIPSE
-
-                    // for every x, calculate y and split between top and bottom pixel
-                    for x_coord in x..(x_upperlimit + 1) {
-                        let y_coord: f64 = y0 + (vertical_axis as f64) * (1.0 - ((x_coord as f64 - x0) / horizontal_axis as f64).powi(2)).sqrt();
-
-                        if x_coord == x_upperlimit {
-                            y_upperlimit = y_coord.floor() as usize;
-                        }
-
-                        if (y_coord - y_coord.round()).abs() < 0.00001 {
-                            // if point is very close to integer, just draw it on that pixel, and mirror to other 3 symmetric pixels
-                            self.image_data[self.width * (self.height - 1 - (y_coord.round() as usize)) + x_coord] = color;
-                            self.image_data[self.width * (self.height - 1 - (2 * y - (y_coord.round() as usize))) + x_coord] = color;
-                            if x_coord != x {
-                                // new x coord (mirrored to left) ===> x_coord = x - (x_coord - x)
-                                self.image_data[self.width * (self.height - 1 - (y_coord.round() as usize)) + (2 * x - x_coord)] = color;
-                                self.image_data[self.width * (self.height - 1 - (2 * y - (y_coord.round() as usize))) + (2 * x - x_coord)] = color;
-                            }
-                        } else {
-                            // split point between two pixels, and mirror to other 3 symmetric pixels
-                            let pix1_percentage: f64 = y_coord - y_coord.floor();
-                            let pix2_percentage: f64 = 1.0 - pix1_percentage;
-                            let pix1_ind: usize = self.width * (self.height - 1 - (y_coord.ceil() as usize)) + x_coord;
-                            let pix2_ind: usize = pix1_ind + self.width;
-                            for channel in 0..color.len() {
-                                // background color aware ===> color = color + (new_color - color) * color_percentage ===> color = color * (1 - color_percentage) + new_color * color_percentage
-                                self.image_data[pix1_ind][channel] = ((self.image_data[pix1_ind][channel] as f64) * (pix2_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-                                self.image_data[pix2_ind][channel] = ((self.image_data[pix2_ind][channel] as f64) * (pix1_percentage) + (color[channel] as f64) * pix2_percentage).round() as u8;
-
-                                self.image_data[pix1_ind - 2 * (x_coord - x)][channel] = ((self.image_data[pix1_ind - 2 * (x_coord - x)][channel] as f64) * (pix2_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-                                self.image_data[pix2_ind - 2 * (x_coord - x)][channel] = ((self.image_data[pix2_ind - 2 * (x_coord - x)][channel] as f64) * (pix1_percentage) + (color[channel] as f64) * pix2_percentage).round() as u8;
-
-                                self.image_data[pix1_ind + 2 * (y_coord.ceil() as usize - y) * self.width][channel] = ((self.image_data[pix1_ind + 2 * (y_coord.ceil() as usize - y) * self.width][channel] as f64) * (pix2_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-                                self.image_data[pix2_ind + 2 * (y_coord.floor() as usize - y) * self.width][channel] = ((self.image_data[pix2_ind + 2 * (y_coord.floor() as usize - y) * self.width][channel] as f64) * (pix1_percentage) + (color[channel] as f64) * pix2_percentage).round() as u8;
-
-                                self.image_data[pix1_ind + 2 * (y_coord.ceil() as usize - y) * self.width - 2 * (x_coord - x)][channel] = ((self.image_data[pix1_ind + 2 * (y_coord.ceil() as usize - y) * self.width - 2 * (x_coord - x)][channel] as f64) * (pix2_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-                                self.image_data[pix2_ind + 2 * (y_coord.floor() as usize - y) * self.width - 2 * (x_coord - x)][channel] = ((self.image_data[pix2_ind + 2 * (y_coord.floor() as usize - y) * self.width - 2 * (x_coord - x)][channel] as f64) * (pix1_percentage) + (color[channel] as f64) * pix2_percentage).round() as u8;
+        if opacity >= 0.0 {
+            let paint: Paint<P> = color.into();
+            let path: Path = Path::new().move_to(start.0, start.1).cubic_to(control1.0, control1.1, control2.0, control2.1, end.0, end.1);
+            raster::stroke_path(self.width, self.height, &path, thickness, LineJoin::Miter, LineCap::Butt, &mut |x, y, coverage| self.paint_pixel(x, y, &paint, coverage * opacity));
+        }
+    }
 
-                            }
-                        }
-                    }
-
-                    // for every y, calculate x and split between left and right pixel (up to y_upperlimit - 1 as it is a special case handled separately)
-                    for y_coord in y..y_upperlimit {
-                        let x_coord: f64 = x0 + (horizontal_axis as f64) * (1.0 - ((y_coord as f64 - y0) / vertical_axis as f64).powi(2)).sqrt();
-
-                        if (x_coord - x_coord.round()).abs() < 0.00001 {
-                            // if point is very close to integer, just draw it on that pixel, and mirror to other 3 symmetric pixels
-                            self.image_data[self.width * (self.height - 1 - y_coord) + x_coord.round() as usize] = color;
-                            self.image_data[self.width * (self.height - 1 - y_coord) + (2 * x - x_coord.round() as usize)] = color;
-                            if y_coord != y {
-                                // new x coord (mirrored to left) ===> x_coord = x - (x_coord - x)
-                                self.image_data[self.width * (self.height - 1 - (2 * y - y_coord)) + x_coord.round() as usize] = color;
-                                self.image_data[self.width * (self.height - 1 - (2 * y - y_coord)) + (2 * x - x_coord.round() as usize)] = color;
-                            }
-                        } else {
-                            // split point between two pixels, and mirror to other 3 symmetric pixels
-                            let pix1_percentage: f64 = x_coord - x_coord.floor();
-                            let pix2_percentage: f64 = 1.0 - pix1_percentage;
-                            let pix_ind: usize = self.width * (self.height - 1 - y_coord) + x_coord.ceil() as usize;
-                            for channel in 0..color.len() {
-                                // background color aware ===> color = color + (new_color - color) * color_percentage ===> color = color * (1 - color_percentage) + new_color * color_percentage
-                                self.image_data[pix_ind][channel] = ((self.image_data[pix_ind][channel] as f64) * (pix2_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-                                self.image_data[pix_ind - 1][channel] = ((self.image_data[pix_ind - 1][channel] as f64) * (pix1_percentage) + (color[channel] as f64) * pix2_percentage).round() as u8;
-
-                                let mut temp_ind = pix_ind - 2 * (x_coord.ceil() as usize - x);
-                                self.image_data[temp_ind][channel] = ((self.image_data[temp_ind][channel] as f64) * (pix2_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-                                self.image_data[temp_ind + 1][channel] = ((self.image_data[temp_ind + 1][channel] as f64) * (pix1_percentage) + (color[channel] as f64) * pix2_percentage).round() as u8;
-
-                                temp_ind = pix_ind + 2 * (y_coord - y) * self.width;
-                                self.image_data[temp_ind][channel] = ((self.image_data[temp_ind][channel] as f64) * (pix2_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-                                self.image_data[temp_ind - 1][channel] = ((self.image_data[temp_ind - 1][channel] as f64) * (pix1_percentage) + (color[channel] as f64) * pix2_percentage).round() as u8;
-
-                                temp_ind -= 2 * (x_coord.ceil() as usize - x);
-                                self.image_data[temp_ind][channel] = ((self.image_data[temp_ind][channel] as f64) * (pix2_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-                                self.image_data[temp_ind + 1][channel] = ((self.image_data[temp_ind + 1][channel] as f64) * (pix1_percentage) + (color[channel] as f64) * pix2_percentage).round() as u8;
+    pub fn draw_spline(&mut self, points: &[(f64, f64)], color: impl Into<Paint<P>>, thickness: usize, opacity: f64) {
+        //! Draws a new smooth curve through all `points`, one after another (Catmull-Rom spline, see [Path::spline_to()]).
+        //! `color` defines the color of the curve, which can also be a [Paint] like a [Gradient](crate::Gradient) (its alpha, if it has one, is multiplied with `opacity`).
+        //! `thickness` defines how thick the curve will be (measured perpendicular to the curve, centered on it). If set to 0, nothing will be drawn.
+        //! `opacity` sets the transparency of the curve. `<= 0.0` means the curve will be completely transparent, while `>= 1.0` means the curve won't be transparent.
 
-                            }
-                        }
-                    }
-
-                    // special case when y = y_upperlimit, draw only outer pixel (as inner was already filled with something in x loop)
-                    let x_coord: f64 = x0 + (horizontal_axis as f64) * (1.0 - ((y_upperlimit as f64 - y0) / vertical_axis as f64).powi(2)).sqrt();
-                    let pix1_percentage: f64 = x_coord - x_coord.floor();
-                    let pix2_percentage: f64 = 1.0 - pix1_percentage;
-                    let pix_ind: usize = self.width * (self.height - 1 - y_upperlimit) + x_coord.ceil() as usize;
-                    for channel in 0..color.len() {
-                        // background color aware ===> color = color + (new_color - color) * color_percentage ===> color = color * (1 - color_percentage) + new_color * color_percentage
-                        self.image_data[pix_ind][channel] = ((self.image_data[pix_ind][channel] as f64) * (pix2_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-
-                        let mut temp_ind = pix_ind - 2 * (x_coord.ceil() as usize - x);
-                        self.image_data[temp_ind][channel] = ((self.image_data[temp_ind][channel] as f64) * (pix2_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-
-                        temp_ind = pix_ind + 2 * (y_upperlimit - y) * self.width;
-                        self.image_data[temp_ind][channel] = ((self.image_data[temp_ind][channel] as f64) * (pix2_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-
-                        temp_ind -= 2 * (x_coord.ceil() as usize - x);
-                        self.image_data[temp_ind][channel] = ((self.image_data[temp_ind][channel] as f64) * (pix2_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-                    }
-
-                } else {
-                    // DRAW TRANSPARENT ELLIPSE
-
-                    // for every x, calculate y and split between top and bottom pixel
-                    for x_coord in x..(x_upperlimit + 1) {
-                        let y_coord: f64 = y0 + (vertical_axis as f64) * (1.0 - ((x_coord as f64 - x0) / horizontal_axis as f64).powi(2)).sqrt();
-
-                        if x_coord == x_upperlimit {
-                            y_upperlimit = y_coord.floor() as usize;
-                        }
-
-                        if (y_coord - y_coord.round()).abs() < 0.00001 {
-                            // if point is very close to integer, just draw it on that pixel, and mirror to other 3 symmetric pixels
-                            for channel in 0..color.len() {
-                                self.image_data[self.width * (self.height - 1 - (y_coord.round() as usize)) + x_coord][channel] = ((self.image_data[self.width * (self.height - 1 - (y_coord.round() as usize)) + x_coord][channel] as f64) * (1.0 - opacity) + (color[channel] as f64) * opacity).round() as u8;
-                            }
-                            if y_coord.round() as usize != y {
-                                for channel in 0..color.len() {
-                                    self.image_data[self.width * (self.height - 1 - (2 * y - (y_coord.round() as usize))) + x_coord][channel] = ((self.image_data[self.width * (self.height - 1 - (2 * y - (y_coord.round() as usize))) + x_coord][channel] as f64) * (1.0 - opacity) + (color[channel] as f64) * opacity).round() as u8;
-                                }
-                            }
-                            if x_coord != x {
-                                // new x coord (mirrored to left) ===> x_coord = x - (x_coord - x)
-                                for channel in 0..color.len() {
-                                    self.image_data[self.width * (self.height - 1 - (y_coord.round() as usize)) + (2 * x - x_coord)][channel] = ((self.image_data[self.width * (self.height - 1 - (y_coord.round() as usize)) + (2 * x - x_coord)][channel] as f64) * (1.0 - opacity) + (color[channel] as f64) * opacity).round() as u8;
-                                }
-                                if y_coord.round() as usize != y {
-                                    for channel in 0..color.len() {
-                                        self.image_data[self.width * (self.height - 1 - (2 * y - (y_coord.round() as usize))) + (2 * x - x_coord)][channel] = ((self.image_data[self.width * (self.height - 1 - (2 * y - (y_coord.round() as usize))) + (2 * x - x_coord)][channel] as f64) * (1.0 - opacity) + (color[channel] as f64) * opacity).round() as u8;
-                                    }
-                                }
-                            }
-                        } else {
-                            // split point between two pixels, and mirror to other 3 symmetric pixels
-                            let pix1_percentage: f64 = (y_coord - y_coord.floor()) * opacity;
-                            let pix2_percentage: f64 = (y_coord.ceil() - y_coord) * opacity;
-                            let pix1_ind: usize = self.width * (self.height - 1 - (y_coord.ceil() as usize)) + x_coord;
-                            let pix2_ind: usize = pix1_ind + self.width;
-                            for channel in 0..color.len() {
-                                // background color aware ===> color = color + (new_color - color) * color_percentage ===> color = color * (1 - color_percentage) + new_color * color_percentage
-                                self.image_data[pix1_ind][channel] = ((self.image_data[pix1_ind][channel] as f64) * (1.0 - pix1_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-                                self.image_data[pix2_ind][channel] = ((self.image_data[pix2_ind][channel] as f64) * (1.0 - pix2_percentage) + (color[channel] as f64) * pix2_percentage).round() as u8;
-
-                                self.image_data[pix1_ind - 2 * (x_coord - x)][channel] = ((self.image_data[pix1_ind - 2 * (x_coord - x)][channel] as f64) * (1.0 - pix1_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-                                self.image_data[pix2_ind - 2 * (x_coord - x)][channel] = ((self.image_data[pix2_ind - 2 * (x_coord - x)][channel] as f64) * (1.0 - pix2_percentage) + (color[channel] as f64) * pix2_percentage).round() as u8;
-
-                                self.image_data[pix1_ind + 2 * (y_coord.ceil() as usize - y) * self.width][channel] = ((self.image_data[pix1_ind + 2 * (y_coord.ceil() as usize - y) * self.width][channel] as f64) * (1.0 - pix1_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-                                self.image_data[pix2_ind + 2 * (y_coord.floor() as usize - y) * self.width][channel] = ((self.image_data[pix2_ind + 2 * (y_coord.floor() as usize - y) * self.width][channel] as f64) * (1.0 - pix2_percentage) + (color[channel] as f64) * pix2_percentage).round() as u8;
-
-                                self.image_data[pix1_ind + 2 * (y_coord.ceil() as usize - y) * self.width - 2 * (x_coord - x)][channel] = ((self.image_data[pix1_ind + 2 * (y_coord.ceil() as usize - y) * self.width - 2 * (x_coord - x)][channel] as f64) * (1.0 - pix1_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-                                self.image_data[pix2_ind + 2 * (y_coord.floor() as usize - y) * self.width - 2 * (x_coord - x)][channel] = ((self.image_data[pix2_ind + 2 * (y_coord.floor() as usize - y) * self.width - 2 * (x_coord - x)][channel] as f64) * (1.0 - pix2_percentage) + (color[channel] as f64) * pix2_percentage).round() as u8;
+        if opacity >= 0.0 {
+            let paint: Paint<P> = color.into();
+            raster::stroke_path(self.width, self.height, &Path::new().spline_to(points), thickness, LineJoin::Miter, LineCap::Butt, &mut |x, y, coverage| self.paint_pixel(x, y, &paint, coverage * opacity));
+        }
+    }
 
-                            }
-                        }
-                    }
-
-                    // for every y, calculate x and split between left and right pixel (up to y_upperlimit - 1 as it is a special case handled separately)
-                    for y_coord in y..y_upperlimit {
-                        let x_coord: f64 = x0 + (horizontal_axis as f64) * (1.0 - ((y_coord as f64 - y0) / vertical_axis as f64).powi(2)).sqrt();
-
-                        if (x_coord - x_coord.round()).abs() < 0.00001 {
-                            // if point is very close to integer, just draw it on that pixel, and mirror to other 3 symmetric pixels
-                            for channel in 0..color.len() {
-                                self.image_data[self.width * (self.height - 1 - y_coord) + x_coord.round() as usize][channel] = ((self.image_data[self.width * (self.height - 1 - y_coord) + x_coord.round() as usize][channel] as f64) * (1.0 - opacity) + (color[channel] as f64) * opacity).round() as u8;
-                                self.image_data[self.width * (self.height - 1 - y_coord) + (2 * x - x_coord.round() as usize)][channel] = ((self.image_data[self.width * (self.height - 1 - y_coord) + (2 * x - x_coord.round() as usize)][channel] as f64) * (1.0 - opacity) + (color[channel] as f64) * opacity).round() as u8;
-                            }
-                            if y_coord != y {
-                                // new x coord (mirrored to left) ===> x_coord = x - (x_coord - x)
-                                for channel in 0..color.len() {
-                                    self.image_data[self.width * (self.height - 1 - (2 * y - y_coord)) + x_coord.round() as usize][channel] = ((self.image_data[self.width * (self.height - 1 - (2 * y - y_coord)) + x_coord.round() as usize][channel] as f64) * (1.0 - opacity) + (color[channel] as f64) * opacity).round() as u8;
-                                    self.image_data[self.width * (self.height - 1 - (2 * y - y_coord)) + (2 * x - x_coord.round() as usize)][channel] = ((self.image_data[self.width * (self.height - 1 - (2 * y - y_coord)) + (2 * x - x_coord.round() as usize)][channel] as f64) * (1.0 - opacity) + (color[channel] as f64) * opacity).round() as u8;
-                                }
-                            }
-                        } else {
-                            // split point between two pixels, and mirror to other 3 symmetric pixels
-                            let pix1_percentage: f64 = (x_coord - x_coord.floor()) * opacity;
-                            let pix2_percentage: f64 = (x_coord.ceil() - x_coord) * opacity;
-                            let pix_ind: usize = self.width * (self.height - 1 - y_coord) + x_coord.ceil() as usize;
-                            for channel in 0..color.len() {
-                                // background color aware ===> color = color + (new_color - color) * color_percentage ===> color = color * (1 - color_percentage) + new_color * color_percentage
-                                self.image_data[pix_ind][channel] = ((self.image_data[pix_ind][channel] as f64) * (1.0 - pix1_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-                                self.image_data[pix_ind - 1][channel] = ((self.image_data[pix_ind - 1][channel] as f64) * (1.0 - pix2_percentage) + (color[channel] as f64) * pix2_percentage).round() as u8;
-
-                                let mut temp_ind = pix_ind - 2 * (x_coord.ceil() as usize - x);
-                                self.image_data[temp_ind][channel] = ((self.image_data[temp_ind][channel] as f64) * (1.0 - pix1_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-                                self.image_data[temp_ind + 1][channel] = ((self.image_data[temp_ind + 1][channel] as f64) * (1.0 - pix2_percentage) + (color[channel] as f64) * pix2_percentage).round() as u8;
-
-                                temp_ind = pix_ind + 2 * (y_coord - y) * self.width;
-                                self.image_data[temp_ind][channel] = ((self.image_data[temp_ind][channel] as f64) * (1.0 - pix1_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-                                self.image_data[temp_ind - 1][channel] = ((self.image_data[temp_ind - 1][channel] as f64) * (1.0 - pix2_percentage) + (color[channel] as f64) * pix2_percentage).round() as u8;
-
-                                temp_ind -= 2 * (x_coord.ceil() as usize - x);
-                                self.image_data[temp_ind][channel] = ((self.image_data[temp_ind][channel] as f64) * (1.0 - pix1_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-                                self.image_data[temp_ind + 1][channel] = ((self.image_data[temp_ind + 1][channel] as f64) * (1.0 - pix2_percentage) + (color[channel] as f64) * pix2_percentage).round() as u8;
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_arc(&mut self, x: f64, y: f64, horizontal_axis: f64, vertical_axis: f64, start_angle: f64, end_angle: f64, color: impl Into<Paint<P>>, thickness: usize, opacity: f64) {
+        //! Draws a new arc, which is a part of the outline of an ellipse. `x`, `y` are the coordinates of the center of the ellipse.
+        //! `horizontal_axis` defines the half length of the horizontal axis and `vertical_axis` the half length of the vertical axis (use the same value for both to get a part of a circle).
+        //! `start_angle` and `end_angle` (in radians) are the directions from the center where the arc starts and ends, it goes counterclockwise from `start_angle` to `end_angle` (`0.0` points right, `PI / 2` points up).
+        //! `color` defines the color of the arc, which can also be a [Paint] like a [Gradient](crate::Gradient) (its alpha, if it has one, is multiplied with `opacity`).
+        //! `thickness` defines how thick the arc will be. (thickness is added to the inside of the ellipse). If set to 0, nothing will be drawn.
+        //! `opacity` sets the transparency of the arc.
+        //! `<= 0.0` means the arc will be completely transparent, while `>= 1.0` means the arc won't be transparent.
 
-                            }
-                        }
-                    }
-
-                    // special case when y = y_upperlimit, draw only outer pixel (as inner was already filled with something in x loop)
-                    let x_coord: f64 = x0 + (horizontal_axis as f64) * (1.0 - ((y_upperlimit as f64 - y0) / vertical_axis as f64).powi(2)).sqrt();
-                    let pix1_percentage: f64 = (x_coord - x_coord.floor()) * opacity;
-                    let pix_ind: usize = self.width * (self.height - 1 - y_upperlimit) + x_coord.ceil() as usize;
-                    for channel in 0..color.len() {
-                        // background color aware ===> color = color + (new_color - color) * color_percentage ===> color = color * (1 - color_percentage) + new_color * color_percentage
-                        self.image_data[pix_ind][channel] = ((self.image_data[pix_ind][channel] as f64) * (1.0 - pix1_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-
-                        let mut temp_ind = pix_ind - 2 * (x_coord.ceil() as usize - x);
-                        self.image_data[temp_ind][channel] = ((self.image_data[temp_ind][channel] as f64) * (1.0 - pix1_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-
-                        temp_ind = pix_ind + 2 * (y_upperlimit - y) * self.width;
-                        self.image_data[temp_ind][channel] = ((self.image_data[temp_ind][channel] as f64) * (1.0 - pix1_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-
-                        temp_ind -= 2 * (x_coord.ceil() as usize - x);
-                        self.image_data[temp_ind][channel] = ((self.image_data[temp_ind][channel] as f64) * (1.0 - pix1_percentage) + (color[channel] as f64) * pix1_percentage).round() as u8;
-                    }
-                }
-            }
+        if opacity >= 0.0 {
+            let paint: Paint<P> = color.into();
+            raster::arc(self.width, self.height, x, y, horizontal_axis, vertical_axis, start_angle, end_angle, thickness, &mut |x, y, coverage| self.paint_pixel(x, y, &paint, coverage * opacity));
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_chord(&mut self, x: f64, y: f64, horizontal_axis: f64, vertical_axis: f64, start_angle: f64, end_angle: f64, color: impl Into<Paint<P>>, thickness: usize, opacity: f64) {
+        //! Draws a new chord, which is a part of an ellipse cut off by a straight line between the ends of the arc. `x`, `y` are the coordinates of the center of the ellipse.
+        //! `horizontal_axis` defines the half length of the horizontal axis and `vertical_axis` the half length of the vertical axis (use the same value for both to get a part of a circle).
+        //! `start_angle` and `end_angle` (in radians) are the directions from the center where the chord starts and ends, it goes counterclockwise from `start_angle` to `end_angle` (`0.0` points right, `PI / 2` points up).
+        //! `color` defines the color of the chord, which can also be a [Paint] like a [Gradient](crate::Gradient) (its alpha, if it has one, is multiplied with `opacity`).
+        //! `thickness` defines how thick the chord will be. (thickness is added to the inside of the ellipse). If set to 0, the chord will be filled.
+        //! `opacity` sets the transparency of the chord.
+        //! `<= 0.0` means the chord will be completely transparent, while `>= 1.0` means the chord won't be transparent.
+
+        if opacity >= 0.0 {
+            let paint: Paint<P> = color.into();
+            raster::chord(self.width, self.height, x, y, horizontal_axis, vertical_axis, start_angle, end_angle, thickness, &mut |x, y, coverage| self.paint_pixel(x, y, &paint, coverage * opacity));
         }
     }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_pie(&mut self, x: f64, y: f64, horizontal_axis: f64, vertical_axis: f64, start_angle: f64, end_angle: f64, color: impl Into<Paint<P>>, thickness: usize, opacity: f64) {
+        //! Draws a new pie slice, which is a part of an ellipse between the arc and two lines from its ends to the center. `x`, `y` are the coordinates of the center of the ellipse.
+        //! `horizontal_axis` defines the half length of the horizontal axis and `vertical_axis` the half length of the vertical axis (use the same value for both to get a part of a circle).
+        //! `start_angle` and `end_angle` (in radians) are the directions from the center where the pie slice starts and ends, it goes counterclockwise from `start_angle` to `end_angle` (`0.0` points right, `PI / 2` points up).
+        //! `color` defines the color of the pie slice, which can also be a [Paint] like a [Gradient](crate::Gradient) (its alpha, if it has one, is multiplied with `opacity`).
+        //! `thickness` defines how thick the pie slice will be. (thickness is added to the inside of the ellipse). If set to 0, the pie slice will be filled.
+        //! `opacity` sets the transparency of the pie slice.
+        //! `<= 0.0` means the pie slice will be completely transparent, while `>= 1.0` means the pie slice won't be transparent.
+
+        if opacity >= 0.0 {
+            let paint: Paint<P> = color.into();
+            raster::pie(self.width, self.height, x, y, horizontal_axis, vertical_axis, start_angle, end_angle, thickness, &mut |x, y, coverage| self.paint_pixel(x, y, &paint, coverage * opacity));
+        }
+    }
+
+    pub fn fill_path(&mut self, path: &Path, fill_rule: FillRule, color: impl Into<Paint<P>>, opacity: f64) {
+        //! Fills the inside of a [Path] (every subpath is treated as closed).
+        //! Only the exact area inside of the path is covered, so paths sharing an edge fit together without overlapping
+        //! (unlike [Image::draw_polygon()], which includes the pixels on the edges like the other shapes).
+        //! `fill_rule` decides which parts of a path with self-intersecting or nested subpaths are inside of it (see [FillRule]).
+        //! `color` defines the color of the path, which can also be a [Paint] like a [Gradient](crate::Gradient) (its alpha, if it has one, is multiplied with `opacity`).
+        //! `opacity` sets the transparency of the path.
+        //! `<= 0.0` means the path will be completely transparent, while `>= 1.0` means the path won't be transparent.
+
+        if opacity >= 0.0 {
+            let paint: Paint<P> = color.into();
+            raster::fill_path(self.width, self.height, path, fill_rule, &mut |x, y, coverage| self.paint_pixel(x, y, &paint, coverage * opacity));
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn stroke_path(&mut self, path: &Path, join: LineJoin, cap: LineCap, color: impl Into<Paint<P>>, thickness: usize, opacity: f64) {
+        //! Draws the lines and curves of a [Path].
+        //! `join` defines the shape of the joints between the lines (see [LineJoin]).
+        //! `cap` defines the shape of the ends of the subpaths that aren't closed (see [LineCap]).
+        //! `color` defines the color of the path, which can also be a [Paint] like a [Gradient](crate::Gradient) (its alpha, if it has one, is multiplied with `opacity`).
+        //! `thickness` defines how thick the path will be (measured perpendicular to the lines, centered on them). If set to 0, nothing will be drawn.
+        //! `opacity` sets the transparency of the path. `<= 0.0` means the path will be completely transparent, while `>= 1.0` means the path won't be transparent.
+        //! Every pixel is blended only once, so overlapping parts of a translucent path are not darker.
+
+        if opacity >= 0.0 {
+            let paint: Paint<P> = color.into();
+            raster::stroke_path(self.width, self.height, path, thickness, join, cap, &mut |x, y, coverage| self.paint_pixel(x, y, &paint, coverage * opacity));
+        }
+    }
+}
+
+impl Image<[f32; 3]> {
+    pub fn tone_map(&self, exposure: f64, tone_mapping: ToneMapping) -> ImageRGB8 {
+        //! Returns the image converted to [ImageRGB8] (sRGB encoded).
+        //! ```exposure``` is in stops, the values are multiplied with `2^exposure` before they are tone mapped (`0.0` keeps the values unchanged).
+        //! ```tone_mapping``` is the operator that maps values above `1.0` to the displayable range.
+
+        let scale: f64 = exposure.exp2();
+        let img: Vec<[u8; 3]> = self.image_data.iter().map(|pixel| {
+            let mapped: [f32; 3] = pixel.map(|channel| tone_mapping.apply(channel as f64 * scale) as f32);
+            mapped.convert()
+        }).collect();
+        Image { width: self.width, height: self.height, image_data: img.clone(), background_data: Background::Image(img), blend_mode: self.blend_mode, linear_blending: self.linear_blending }
+    }
 }
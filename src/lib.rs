@@ -9,28 +9,54 @@
 //! let background_color: [u8; 3] = [255, 155, 0];
 //! let mut image: ImageRGB8 = ImageRGB8::new(640, 360, background_color);
 //!
-//! image.draw_line(0, 0, 639, 359, [255, 255, 255], 1, 1.0);
-//! image.draw_line(0, 359, 639, 0, [255, 255, 255], 1, 1.0);
-//! image.draw_rectangle(0, 0, 639, 359, [255, 255, 255], 3, 1.0);
-//! image.draw_ellipse(319, 179, 300, 150, [0, 0, 0], 0, 0.5);
-//! image.draw_circle(149, 179, 30, [255, 255, 255], 0, 1.0);
-//! image.draw_circle(149, 179, 20, [0, 0, 0], 0, 1.0);
-//! image.draw_circle(489, 179, 30, [255, 255, 255], 0, 1.0);
-//! image.draw_circle(489, 179, 20, [0, 0, 0], 0, 1.0);
-//! image.draw_ellipse(319, 90, 80, 30, [255, 255, 255], 0, 1.0);
-//! image.draw_ellipse(319, 90, 60, 20, [0, 0, 0], 0, 1.0);
+//! image.draw_line(0.0, 0.0, 639.0, 359.0, [255, 255, 255], 1, 1.0);
+//! image.draw_line(0.0, 359.0, 639.0, 0.0, [255, 255, 255], 1, 1.0);
+//! image.draw_rectangle(0.0, 0.0, 639.0, 359.0, [255, 255, 255], 3, 1.0);
+//! image.draw_ellipse(319.0, 179.0, 300.0, 150.0, [0, 0, 0], 0, 0.5);
+//! image.draw_circle(149.0, 179.0, 30.0, [255, 255, 255], 0, 1.0);
+//! image.draw_circle(149.0, 179.0, 20.0, [0, 0, 0], 0, 1.0);
+//! image.draw_circle(489.0, 179.0, 30.0, [255, 255, 255], 0, 1.0);
+//! image.draw_circle(489.0, 179.0, 20.0, [0, 0, 0], 0, 1.0);
+//! image.draw_ellipse(319.0, 90.0, 80.0, 30.0, [255, 255, 255], 0, 1.0);
+//! image.draw_ellipse(319.0, 90.0, 60.0, 20.0, [0, 0, 0], 0, 1.0);
 //!
 //! let bytes: &[u8] = image.to_bytes(); // get image as bytes
 //! // image.to_png("image.png").unwrap(); // export image as PNG
 //! ```
 //!
-//! **Shapes:** line, rectangle, ellipse, circle
+//! Coordinates are given as `f64`, whole numbers are the centers of pixels and `(0.0, 0.0)` is the center of the bottom left pixel.
+//! Coordinates can be negative or fractional, the anti-aliasing reflects the sub-pixel position of the shape.
+//! Shapes can extend past the image bounds, only the part of the shape inside the image is drawn.
 //!
-//! **Colorspaces:** RGB8
+//! **Shapes:** line, polyline, quadratic and cubic Bezier curve, spline, rectangle, rounded rectangle, ellipse, circle, rotated rectangle and ellipse, arc, chord, pie slice, polygon, triangle, regular polygon, star, arrow, path made of lines and curves (lines and outlines can be dashed or dotted)
+//!
+//! **Paints:** single color, linear, radial and conic gradient, tiled or stretched image pattern, hatch (diagonal, cross-hatch, horizontal, dots)
+//!
+//! **Blend modes:** normal, multiply, screen, overlay, darken, lighten, difference, additive, color dodge, color burn, XOR
+//!
+//! **Colorspaces:** Gray8, Gray16, GrayA8, GrayA16, RGB8, RGBA8, RGB16, RGBA16, RGB32F
+//!
+//! All image types are aliases of the generic [Image] struct, which works with any pixel format implementing the [Pixel] trait.
+//! Colors are blended on the stored (sRGB encoded) values, [Image::set_linear_blending()] switches an image to gamma-correct blending in linear light.
 
 pub mod image;
+pub mod paint;
+pub mod path;
+pub mod pixel;
+mod raster;
+pub mod style;
+#[doc(inline)]
+pub use image::{Image, ImageGray8, ImageGray16, ImageGrayA8, ImageGrayA16, ImageRGB8, ImageRGBA8, ImageRGB16, ImageRGBA16, ImageRGB32F};
+#[doc(inline)]
+pub use image::ToneMapping;
+#[doc(inline)]
+pub use paint::{Gradient, Hatch, HatchStyle, Paint, Pattern, Spread};
+#[doc(inline)]
+pub use path::Path;
+#[doc(inline)]
+pub use pixel::{BlendMode, Pixel};
 #[doc(inline)]
-pub use image::ImageRGB8;
+pub use style::{ArrowHead, FillRule, LineCap, LineJoin};
 
 #[cfg(test)]
 mod tests {
@@ -41,24 +67,868 @@ mod tests {
         let background_color: [u8; 3] = [255, 155, 0];
         let mut image: ImageRGB8 = ImageRGB8::new(640, 360, background_color);
 
-        image.draw_line(0, 0, 639, 359, [255, 255, 255], 1, 1.0);
-        image.draw_line(0, 359, 639, 0, [255, 255, 255], 1, 1.0);
+        image.draw_line(0.0, 0.0, 639.0, 359.0, [255, 255, 255], 1, 1.0);
+        image.draw_line(0.0, 359.0, 639.0, 0.0, [255, 255, 255], 1, 1.0);
 
-        image.draw_rectangle(0, 0, 639, 359, [255, 255, 255], 3, 1.0);
+        image.draw_rectangle(0.0, 0.0, 639.0, 359.0, [255, 255, 255], 3, 1.0);
 
-        image.draw_ellipse(319, 179, 300, 150, [0, 0, 0], 0, 0.5);
+        image.draw_ellipse(319.0, 179.0, 300.0, 150.0, [0, 0, 0], 0, 0.5);
 
-        image.draw_circle(149, 179, 30, [255, 255, 255], 0, 1.0);
-        image.draw_circle(149, 179, 20, [0, 0, 0], 0, 1.0);
+        image.draw_circle(149.0, 179.0, 30.0, [255, 255, 255], 0, 1.0);
+        image.draw_circle(149.0, 179.0, 20.0, [0, 0, 0], 0, 1.0);
 
-        image.draw_circle(489, 179, 30, [255, 255, 255], 0, 1.0);
-        image.draw_circle(489, 179, 20, [0, 0, 0], 0, 1.0);
+        image.draw_circle(489.0, 179.0, 30.0, [255, 255, 255], 0, 1.0);
+        image.draw_circle(489.0, 179.0, 20.0, [0, 0, 0], 0, 1.0);
 
 
-        image.draw_ellipse(319, 90, 80, 30, [255, 255, 255], 0, 1.0);
-        image.draw_ellipse(319, 90, 60, 20, [0, 0, 0], 0, 1.0);
+        image.draw_ellipse(319.0, 90.0, 80.0, 30.0, [255, 255, 255], 0, 1.0);
+        image.draw_ellipse(319.0, 90.0, 60.0, 20.0, [0, 0, 0], 0, 1.0);
 
         let _bytes: &[u8] = image.to_bytes();
         // image.to_png("image.png").unwrap();
     }
+
+    #[test]
+    fn thick_line() {
+        let mut image: ImageRGB8 = ImageRGB8::new(20, 20, [0, 0, 0]);
+
+        // horizontal line 3 pixels thick covers exactly 3 rows
+        image.draw_line(2.0, 10.0, 17.0, 10.0, [255, 255, 255], 3, 1.0);
+        for y in 0..20 {
+            let expected: u8 = if (9..12).contains(&y) { 255 } else { 0 };
+            assert_eq!(image.get_pixel(10, y).unwrap(), [expected; 3]);
+        }
+
+        // diagonal line keeps its thickness perpendicular to the line (endpoints given in reverse order)
+        image.clear();
+        image.draw_line(17.0, 17.0, 2.0, 2.0, [255, 255, 255], 4, 1.0);
+        let column_sum: f64 = (0..20).map(|y| image.get_pixel(10, y).unwrap()[0] as f64 / 255.0).sum();
+        assert!((column_sum - 4.0 * std::f64::consts::SQRT_2).abs() < 0.05);
+
+        // ends are cut perpendicular to the line at the end points, whatever the slope is
+        let mut image: ImageRGB8 = ImageRGB8::new(40, 40, [0, 0, 0]);
+        image.draw_line(8.0, 8.0, 30.0, 30.0, [255, 255, 255], 6, 1.0);
+        let length: f64 = 22.0 * std::f64::consts::SQRT_2;
+        for x in 0..40 {
+            for y in 0..40 {
+                // distance of the pixel center from the starting point along the line
+                let along: f64 = (x as f64 + y as f64 - 16.0) / std::f64::consts::SQRT_2;
+                if along < -0.75 || along > length + 0.75 {
+                    assert_eq!(image.get_pixel(x, y).unwrap(), [0; 3]);
+                }
+            }
+        }
+        assert_eq!(image.get_pixel(8, 8).unwrap(), [128; 3]);
+        assert_eq!(image.get_pixel(30, 30).unwrap(), [128; 3]);
+        assert_eq!(image.get_pixel(9, 7).unwrap(), [128; 3]);
+        assert_eq!(image.get_pixel(10, 8).unwrap(), [255; 3]);
+    }
+
+    #[test]
+    fn thick_circle() {
+        let mut image: ImageRGB8 = ImageRGB8::new(41, 41, [0, 0, 0]);
+
+        // ring 4 pixels thick is added to the inside of the circle, center stays untouched
+        image.draw_circle(20.0, 20.0, 15.0, [255, 255, 255], 4, 1.0);
+        for x in 0..41 {
+            let expected: u8 = if (5..9).contains(&x) || (32..36).contains(&x) { 255 } else { 0 };
+            assert_eq!(image.get_pixel(x, 20).unwrap(), [expected; 3]);
+        }
+    }
+
+    #[test]
+    fn clipped_shapes() {
+        // shapes partly outside of the image are drawn the same as on a larger image
+        let mut small: ImageRGB8 = ImageRGB8::new(30, 30, [0, 0, 0]);
+        let mut large: ImageRGB8 = ImageRGB8::new(60, 60, [0, 0, 0]);
+        for image in [&mut small, &mut large] {
+            image.draw_circle(5.0, 25.0, 12.0, [255, 0, 0], 3, 1.0);
+            image.draw_ellipse(28.0, 3.0, 10.0, 6.0, [0, 255, 0], 0, 0.5);
+            image.draw_rectangle(20.0, 20.0, 40.0, 40.0, [0, 0, 255], 2, 1.0);
+        }
+        for x in 0..30 {
+            for y in 0..30 {
+                assert_eq!(small.get_pixel(x, y).unwrap(), large.get_pixel(x, y).unwrap());
+            }
+        }
+        // right and top side of the rectangle are outside of the small image
+        assert_eq!(small.get_pixel(29, 25).unwrap(), [0, 0, 0]);
+        assert_eq!(small.get_pixel(25, 29).unwrap(), [0, 0, 0]);
+    }
+
+    #[test]
+    fn sub_pixel_coordinates() {
+        let mut image: ImageRGB8 = ImageRGB8::new(20, 20, [0, 0, 0]);
+
+        // line between two rows of pixels (starting outside of the image) is split evenly between them
+        image.draw_line(-5.0, 10.5, 25.0, 10.5, [255, 255, 255], 1, 1.0);
+        for x in 0..20 {
+            assert_eq!(image.get_pixel(x, 10).unwrap(), [128; 3]);
+            assert_eq!(image.get_pixel(x, 11).unwrap(), [128; 3]);
+        }
+
+        // circle with the center outside of the image
+        image.clear();
+        image.draw_circle(-3.0, 5.0, 6.25, [255, 255, 255], 0, 1.0);
+        assert_eq!(image.get_pixel(3, 5).unwrap(), [255; 3]);
+        assert_eq!(image.get_pixel(4, 5).unwrap(), [64; 3]);
+        assert_eq!(image.get_pixel(5, 5).unwrap(), [0; 3]);
+    }
+
+    #[test]
+    fn rgba_image() {
+        let mut image: ImageRGBA8 = ImageRGBA8::new(20, 20, [0, 0, 0, 0]);
+
+        // drawing onto transparent pixels keeps the color and sets the alpha
+        image.draw_rectangle(0.0, 0.0, 9.0, 19.0, [255, 0, 0, 255], 0, 0.5);
+        assert_eq!(image.get_pixel(5, 5).unwrap(), [255, 0, 0, 128]);
+        // source-over compositing of translucent colors
+        image.draw_rectangle(5.0, 0.0, 14.0, 19.0, [0, 0, 255, 128], 0, 1.0);
+        assert_eq!(image.get_pixel(7, 5).unwrap(), [85, 0, 170, 192]);
+        assert_eq!(image.get_pixel(12, 5).unwrap(), [0, 0, 255, 128]);
+        assert_eq!(image.get_pixel(17, 5).unwrap(), [0, 0, 0, 0]);
+
+        // alpha channel survives saving to and reading from PNG
+        let path = std::env::temp_dir().join("tinydraw_rgba_image.png");
+        image.to_png(path.to_str().unwrap()).unwrap();
+        let loaded: ImageRGBA8 = ImageRGBA8::from_png(path.to_str().unwrap()).unwrap();
+        assert_eq!(loaded.image_data, image.image_data);
+        std::fs::remove_file(path).unwrap();
+
+        // premultiplied bytes
+        let premultiplied: Vec<u8> = image.to_premultiplied_bytes();
+        assert_eq!(&premultiplied[(20 * 14 + 12) * 4..(20 * 14 + 13) * 4], &[0, 0, 128, 128]);
+        let restored: ImageRGBA8 = ImageRGBA8::from_premultiplied_bytes(20, 20, &premultiplied).unwrap();
+        assert_eq!(restored.get_pixel(12, 5).unwrap(), [0, 0, 255, 128]);
+    }
+
+    #[test]
+    fn pixel_formats() {
+        // the same drawing on different pixel formats gives the same (converted) result
+        let mut rgb8: ImageRGB8 = ImageRGB8::new(20, 20, [0, 0, 0]);
+        let mut gray8: ImageGray8 = ImageGray8::new(20, 20, [0]);
+        let mut rgb16: ImageRGB16 = ImageRGB16::new(20, 20, [0, 0, 0]);
+        rgb8.draw_circle(10.0, 10.0, 6.3, [255, 255, 255], 2, 0.75);
+        gray8.draw_circle(10.0, 10.0, 6.3, [255], 2, 0.75);
+        rgb16.draw_circle(10.0, 10.0, 6.3, [65535, 65535, 65535], 2, 0.75);
+        assert_eq!(gray8.convert::<[u8; 3]>().image_data, rgb8.image_data);
+        assert_eq!(rgb16.convert::<[u8; 3]>().image_data, rgb8.image_data);
+
+        // colors are converted to gray as luminance, alpha is kept
+        let mut gray_alpha: ImageGrayA8 = ImageGrayA8::new(20, 20, [0, 0]);
+        gray_alpha.draw_rectangle(0.0, 0.0, 19.0, 19.0, [255, 128], 0, 1.0);
+        let rgba8: ImageRGBA8 = gray_alpha.convert();
+        assert_eq!(rgba8.get_pixel(3, 3).unwrap(), [255, 255, 255, 128]);
+        assert_eq!([0u8, 255, 0].convert::<[u8; 1]>(), [182]);
+    }
+
+    #[test]
+    fn gray_image() {
+        let mut image: ImageGray16 = ImageGray16::new(20, 20, [1000]);
+        image.draw_line(0.0, 3.0, 19.0, 15.0, [60000], 2, 0.7);
+        image.draw_circle(10.0, 10.0, 5.5, [30000], 0, 0.3);
+
+        // gray images are saved as gray PNG files with their bit depth
+        let path = std::env::temp_dir().join("tinydraw_gray_image.png");
+        image.to_png(path.to_str().unwrap()).unwrap();
+        let info = png::Decoder::new(std::fs::File::open(&path).unwrap()).read_info().unwrap().info().clone();
+        assert_eq!((info.color_type, info.bit_depth), (png::ColorType::Grayscale, png::BitDepth::Sixteen));
+        let loaded: ImageGray16 = ImageGray16::from_png(path.to_str().unwrap()).unwrap();
+        assert_eq!(loaded.image_data, image.image_data);
+
+        // and can be read into any other image type
+        let loaded: ImageGray8 = ImageGray8::from_png(path.to_str().unwrap()).unwrap();
+        assert_eq!(loaded.image_data, image.convert::<[u8; 1]>().image_data);
+        loaded.to_png(path.to_str().unwrap()).unwrap();
+        let loaded_rgb: ImageRGB8 = ImageRGB8::from_png(path.to_str().unwrap()).unwrap();
+        assert_eq!(loaded_rgb.get_pixel(10, 10).unwrap(), [loaded.get_pixel(10, 10).unwrap()[0]; 3]);
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn image_16_bit() {
+        // 16 bit images keep their precision through drawing and PNG files
+        let mut image: ImageRGBA16 = ImageRGBA16::new(20, 20, [0, 0, 0, 0]);
+        image.draw_rectangle(2.0, 2.0, 17.0, 17.0, [1000, 20000, 65535, 65535], 0, 0.3);
+        image.draw_ellipse(10.0, 10.0, 8.0, 4.5, [50001, 3, 700, 40000], 2, 1.0);
+        assert_eq!(image.get_pixel(5, 5).unwrap(), [1000, 20000, 65535, 19661]);
+
+        let path = std::env::temp_dir().join("tinydraw_image_16_bit.png");
+        image.to_png(path.to_str().unwrap()).unwrap();
+        let info = png::Decoder::new(std::fs::File::open(&path).unwrap()).read_info().unwrap().info().clone();
+        assert_eq!((info.color_type, info.bit_depth), (png::ColorType::Rgba, png::BitDepth::Sixteen));
+        let loaded: ImageRGBA16 = ImageRGBA16::from_png(path.to_str().unwrap()).unwrap();
+        assert_eq!(loaded.image_data, image.image_data);
+
+        // 16 bit PNG files can be read into 8 bit images
+        let loaded: ImageRGBA8 = ImageRGBA8::from_png(path.to_str().unwrap()).unwrap();
+        assert_eq!(loaded.get_pixel(5, 5).unwrap(), [4, 78, 255, 77]);
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn hdr_image() {
+        // drawing is accumulated in linear light at full precision
+        let mut image: ImageRGB32F = ImageRGB32F::new(20, 20, [0.0, 0.0, 0.0]);
+        for _ in 0..100 {
+            image.draw_rectangle(0.0, 0.0, 19.0, 19.0, [1.0, 0.5, 0.25], 0, 0.01);
+        }
+        let pixel: [f32; 3] = image.get_pixel(3, 3).unwrap();
+        assert!((pixel[0] - 0.634).abs() < 0.001 && (pixel[2] - 0.158).abs() < 0.001);
+
+        // values are converted to sRGB
+        assert_eq!([0.5f32, 0.5, 0.5].convert::<[u8; 3]>(), [188, 188, 188]);
+        assert_eq!([188u8, 188, 188].convert::<[f32; 3]>().convert::<[u8; 3]>(), [188, 188, 188]);
+
+        // values above 1.0 are compressed by tone mapping
+        image.set_pixel(0, 0, [4.0, 1.0, 0.0]);
+        assert_eq!(image.tone_map(0.0, ToneMapping::Clamp).get_pixel(0, 0).unwrap(), [255, 255, 0]);
+        assert_eq!(image.tone_map(0.0, ToneMapping::Reinhard).get_pixel(0, 0).unwrap(), [231, 188, 0]);
+        assert_eq!(image.tone_map(-2.0, ToneMapping::Clamp).get_pixel(0, 0).unwrap(), [255, 137, 0]);
+        let filmic: [u8; 3] = image.tone_map(0.0, ToneMapping::Filmic).get_pixel(0, 0).unwrap();
+        assert!(filmic[0] > filmic[1] && filmic[0] < 255);
+    }
+
+    #[test]
+    fn polygon() {
+        // polygon with the corners of a rectangle is the same as the rectangle
+        let mut polygon: ImageRGB8 = ImageRGB8::new(30, 30, [0, 0, 0]);
+        let mut rectangle: ImageRGB8 = ImageRGB8::new(30, 30, [0, 0, 0]);
+        for (thickness, color) in [(0, [0, 0, 255]), (3, [255, 0, 0])] {
+            polygon.draw_polygon(&[(3.0, 4.0), (25.0, 4.0), (25.0, 20.0), (3.0, 20.0)], FillRule::NonZero, color, thickness, 0.8);
+            rectangle.draw_rectangle(3.0, 4.0, 25.0, 20.0, color, thickness, 0.8);
+        }
+        assert_eq!(polygon.image_data, rectangle.image_data);
+
+        // center of a pentagram is a hole with even-odd fill rule
+        let pentagram: Vec<(f64, f64)> = (0..5).map(|i| {
+            let angle: f64 = std::f64::consts::FRAC_PI_2 + i as f64 * 0.8 * std::f64::consts::PI;
+            (15.0 + 12.0 * angle.cos(), 15.0 + 12.0 * angle.sin())
+        }).collect();
+        let mut image: ImageRGB8 = ImageRGB8::new(30, 30, [0, 0, 0]);
+        image.draw_polygon(&pentagram, FillRule::EvenOdd, [255, 255, 255], 0, 1.0);
+        assert_eq!(image.get_pixel(15, 15).unwrap(), [0, 0, 0]);
+        assert_eq!(image.get_pixel(15, 24).unwrap(), [255, 255, 255]);
+        image.draw_polygon(&pentagram, FillRule::NonZero, [255, 255, 255], 0, 1.0);
+        assert_eq!(image.get_pixel(15, 15).unwrap(), [255, 255, 255]);
+
+        // anti-aliased edges of a triangle add up to its area (with edges moved half a pixel outwards)
+        let mut image: ImageRGB32F = ImageRGB32F::new(30, 30, [0.0, 0.0, 0.0]);
+        image.draw_polygon(&[(5.2, 5.0), (25.0, 7.3), (12.6, 24.1)], FillRule::EvenOdd, [1.0, 1.0, 1.0], 0, 1.0);
+        let area: f64 = image.image_data.iter().map(|pixel| pixel[0] as f64).sum();
+        assert!((area - 212.53).abs() < 0.5);
+
+        // pixels on the edges belong to the polygon, so translucent polygons sharing an edge overlap on its pixels,
+        // while polygons on neighbouring pixels fit together (like rectangles) and paths sharing an edge don't overlap
+        let mut image: ImageRGB8 = ImageRGB8::new(30, 30, [0, 0, 0]);
+        image.draw_polygon(&[(5.0, 5.0), (15.0, 5.0), (15.0, 24.0), (5.0, 24.0)], FillRule::NonZero, [255, 255, 255], 0, 0.5);
+        image.draw_polygon(&[(15.0, 5.0), (24.0, 5.0), (24.0, 24.0), (15.0, 24.0)], FillRule::NonZero, [255, 255, 255], 0, 0.5);
+        assert_eq!(image.get_pixel(15, 10).unwrap(), [192; 3]);
+        assert_eq!(image.get_pixel(14, 10).unwrap(), [128; 3]);
+        image.clear();
+        image.draw_polygon(&[(5.0, 5.0), (14.0, 5.0), (14.0, 24.0), (5.0, 24.0)], FillRule::NonZero, [255, 255, 255], 0, 0.5);
+        image.draw_polygon(&[(15.0, 5.0), (24.0, 5.0), (24.0, 24.0), (15.0, 24.0)], FillRule::NonZero, [255, 255, 255], 0, 0.5);
+        for x in 5..25 {
+            assert_eq!(image.get_pixel(x, 10).unwrap(), [128; 3]);
+        }
+    }
+
+    #[test]
+    fn polyline() {
+        let mut image: ImageRGB8 = ImageRGB8::new(30, 30, [0, 0, 0]);
+
+        // translucent lines crossing each other and their joints are blended only once
+        let points: [(f64, f64); 4] = [(3.0, 5.0), (25.0, 5.0), (25.0, 25.0), (15.0, 0.0)];
+        image.draw_polyline(&points, LineJoin::Miter, LineCap::Butt, [255, 255, 255], 3, 0.5);
+        assert_eq!(image.get_pixel(10, 5).unwrap(), [128; 3]);
+        assert_eq!(image.get_pixel(25, 5).unwrap(), [128; 3]);
+        assert_eq!(image.get_pixel(17, 5).unwrap(), [128; 3]);
+        // miter join covers the outer corner, bevel join cuts it off
+        assert_eq!(image.get_pixel(26, 4).unwrap(), [128; 3]);
+        image.clear();
+        image.draw_polyline(&points, LineJoin::Bevel, LineCap::Butt, [255, 255, 255], 3, 0.5);
+        assert_eq!(image.get_pixel(26, 4).unwrap(), [16; 3]);
+
+        // butt cap ends at the end point, square cap extends half of the thickness past it
+        assert_eq!(image.get_pixel(3, 5).unwrap(), [64; 3]);
+        assert_eq!(image.get_pixel(2, 5).unwrap(), [0; 3]);
+        image.clear();
+        image.draw_polyline(&points, LineJoin::Round, LineCap::Square, [255, 255, 255], 3, 0.5);
+        assert_eq!(image.get_pixel(2, 5).unwrap(), [128; 3]);
+        assert_eq!(image.get_pixel(1, 5).unwrap(), [0; 3]);
+    }
+
+    #[test]
+    fn bezier_curves() {
+        // curves with control points on the line between the end points are straight lines
+        let mut curve: ImageRGB8 = ImageRGB8::new(30, 30, [0, 0, 0]);
+        let mut line: ImageRGB8 = ImageRGB8::new(30, 30, [0, 0, 0]);
+        curve.draw_quadratic_bezier((3.0, 10.0), (15.0, 10.0), (27.0, 10.0), [255, 0, 0], 3, 1.0);
+        curve.draw_cubic_bezier((3.0, 20.0), (10.0, 20.0), (20.0, 20.0), (27.0, 20.0), [0, 255, 0], 2, 1.0);
+        line.draw_polyline(&[(3.0, 10.0), (27.0, 10.0)], LineJoin::Miter, LineCap::Butt, [255, 0, 0], 3, 1.0);
+        line.draw_polyline(&[(3.0, 20.0), (27.0, 20.0)], LineJoin::Miter, LineCap::Butt, [0, 255, 0], 2, 1.0);
+        assert_eq!(curve.image_data, line.image_data);
+
+        // top of a symmetric quadratic curve is halfway between the control point and the end points
+        let mut image: ImageRGB8 = ImageRGB8::new(30, 30, [0, 0, 0]);
+        image.draw_quadratic_bezier((5.0, 5.0), (15.0, 25.0), (25.0, 5.0), [255, 255, 255], 1, 1.0);
+        assert_eq!(image.get_pixel(15, 15).unwrap(), [255; 3]);
+        assert_eq!(image.get_pixel(15, 16).unwrap(), [0; 3]);
+        // cubic curve with both control points in the same place is symmetric
+        image.clear();
+        image.draw_cubic_bezier((5.0, 5.0), (15.0, 25.0), (15.0, 25.0), (25.0, 5.0), [255, 255, 255], 2, 1.0);
+        for x in 1..30 {
+            for y in 0..30 {
+                assert_eq!(image.get_pixel(x, y).unwrap(), image.get_pixel(30 - x, y).unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn splines() {
+        // spline through equally spaced points on a line is the same line
+        let mut spline: ImageRGB8 = ImageRGB8::new(30, 30, [0, 0, 0]);
+        let mut line: ImageRGB8 = ImageRGB8::new(30, 30, [0, 0, 0]);
+        spline.draw_spline(&[(3.0, 10.0), (9.0, 10.0), (15.0, 10.0), (21.0, 10.0), (27.0, 10.0)], [255, 255, 255], 3, 1.0);
+        line.draw_polyline(&[(3.0, 10.0), (27.0, 10.0)], LineJoin::Miter, LineCap::Butt, [255, 255, 255], 3, 1.0);
+        assert_eq!(spline.image_data, line.image_data);
+
+        // spline passes through all points (the ends are butt, so only the points between them are fully covered)
+        spline.clear();
+        let points: [(f64, f64); 5] = [(2.0, 5.0), (8.0, 20.0), (15.0, 10.0), (22.0, 25.0), (28.0, 15.0)];
+        spline.draw_spline(&points, [255, 255, 255], 2, 1.0);
+        for &(x, y) in &points[1..4] {
+            assert_eq!(spline.get_pixel(x as usize, y as usize).unwrap(), [255; 3]);
+        }
+        assert_eq!(spline.get_pixel(8, 22).unwrap(), [0; 3]);
+        assert_eq!(spline.get_pixel(15, 8).unwrap(), [0; 3]);
+    }
+
+    #[test]
+    fn gradients() {
+        use std::f64::consts::PI;
+
+        // linear gradient mixes the colors of the stops between them and pads them outside
+        let mut image: ImageRGB8 = ImageRGB8::new(30, 30, [0, 0, 0]);
+        let stops: [(f64, [u8; 3]); 2] = [(0.0, [255, 0, 0]), (1.0, [0, 0, 255])];
+        image.draw_rectangle(0.0, 0.0, 29.0, 29.0, Gradient::linear(5.0, 0.0, 25.0, 0.0, &stops, Spread::Pad), 0, 1.0);
+        assert_eq!(image.get_pixel(2, 10).unwrap(), [255, 0, 0]);
+        assert_eq!(image.get_pixel(15, 10).unwrap(), [128, 0, 128]);
+        assert_eq!(image.get_pixel(27, 20).unwrap(), [0, 0, 255]);
+        // repeated gradient starts again, reflected gradient goes back
+        image.draw_rectangle(0.0, 0.0, 29.0, 29.0, Gradient::linear(0.0, 0.0, 10.0, 0.0, &stops, Spread::Repeat), 0, 1.0);
+        assert_eq!(image.get_pixel(12, 10).unwrap(), image.get_pixel(2, 10).unwrap());
+        image.draw_rectangle(0.0, 0.0, 29.0, 29.0, Gradient::linear(0.0, 0.0, 10.0, 0.0, &stops, Spread::Reflect), 0, 1.0);
+        assert_eq!(image.get_pixel(12, 10).unwrap(), image.get_pixel(8, 10).unwrap());
+
+        // radial gradient depends on the distance from the center, conic gradient on the direction
+        image.draw_circle(15.0, 15.0, 14.0, Gradient::radial(15.0, 15.0, 10.0, &stops, Spread::Pad), 0, 1.0);
+        assert_eq!(image.get_pixel(15, 15).unwrap(), [255, 0, 0]);
+        assert_eq!(image.get_pixel(20, 15).unwrap(), [128, 0, 128]);
+        assert_eq!(image.get_pixel(15, 10).unwrap(), [128, 0, 128]);
+        image.draw_circle(15.0, 15.0, 14.0, Gradient::conic(15.0, 15.0, 0.0, 2.0 * PI, &stops, Spread::Pad), 0, 1.0);
+        assert!(image.get_pixel(25, 16).unwrap()[0] > 240);
+        assert_eq!(image.get_pixel(5, 15).unwrap(), [128, 0, 128]);
+        assert_eq!(image.get_pixel(15, 5).unwrap(), [64, 0, 191]);
+        // conic gradient shorter than the full turn is spread over the rest of the directions
+        image.draw_circle(15.0, 15.0, 14.0, Gradient::conic(15.0, 15.0, 0.0, PI / 2.0, &stops, Spread::Pad), 0, 1.0);
+        assert_eq!(image.get_pixel(20, 20).unwrap(), [128, 0, 128]);
+        assert_eq!(image.get_pixel(5, 15).unwrap(), [0, 0, 255]);
+        image.draw_circle(15.0, 15.0, 14.0, Gradient::conic(15.0, 15.0, 0.0, PI / 2.0, &stops, Spread::Repeat), 0, 1.0);
+        assert_eq!(image.get_pixel(10, 20).unwrap(), [128, 0, 128]);
+        assert_eq!(image.get_pixel(5, 15).unwrap(), [255, 0, 0]);
+        image.draw_circle(15.0, 15.0, 14.0, Gradient::conic(15.0, 15.0, 0.0, PI / 2.0, &stops, Spread::Reflect), 0, 1.0);
+        assert_eq!(image.get_pixel(5, 15).unwrap(), [255, 0, 0]);
+        assert_eq!(image.get_pixel(15, 5).unwrap(), [0, 0, 255]);
+
+        // gradient is drawn with the coverage and opacity like a single color, transparent stops don't darken the colors
+        let mut rgba: ImageRGBA8 = ImageRGBA8::new(30, 30, [0, 0, 0, 0]);
+        let transparent: Gradient<[u8; 4]> = Gradient::linear(0.0, 0.0, 29.0, 0.0, &[(0.0, [255, 255, 255, 0]), (1.0, [255, 0, 0, 255])], Spread::Pad);
+        rgba.draw_rectangle(0.0, 0.0, 29.0, 29.0, transparent, 0, 1.0);
+        assert_eq!(rgba.get_pixel(0, 0).unwrap()[3], 0);
+        assert_eq!(rgba.get_pixel(29, 0).unwrap(), [255, 0, 0, 255]);
+        let middle: [u8; 4] = rgba.get_pixel(15, 0).unwrap();
+        assert_eq!(middle[1], 0);
+        assert!(middle[3] > 120 && middle[3] < 140);
+        let mut solid: ImageRGB8 = ImageRGB8::new(30, 30, [0, 0, 0]);
+        image.clear();
+        solid.draw_circle(15.0, 15.0, 10.5, [0, 200, 0], 2, 0.5);
+        image.draw_circle(15.0, 15.0, 10.5, Gradient::linear(0.0, 0.0, 1.0, 0.0, &[(0.0, [0, 200, 0])], Spread::Pad), 2, 0.5);
+        assert_eq!(solid.image_data, image.image_data);
+    }
+
+    #[test]
+    fn patterns() {
+        let mut texture: ImageRGB8 = ImageRGB8::new(4, 3, [255, 255, 255]);
+        texture.set_pixel(0, 0, [255, 0, 0]);
+        texture.set_pixel(3, 2, [0, 0, 255]);
+
+        // tiles are copies of the image placed next to each other from the offset
+        let mut image: ImageRGB8 = ImageRGB8::new(30, 30, [0, 0, 0]);
+        image.draw_rectangle(0.0, 0.0, 29.0, 29.0, Pattern::tiled(&texture, 2.0, 1.0), 0, 1.0);
+        for y in 0..30 {
+            for x in 0..30 {
+                assert_eq!(image.get_pixel(x, y).unwrap(), texture.get_pixel((x + 2) % 4, (y + 2) % 3).unwrap());
+            }
+        }
+        // transform moves the pattern after it is placed
+        let mut moved: ImageRGB8 = ImageRGB8::new(30, 30, [0, 0, 0]);
+        moved.draw_rectangle(0.0, 0.0, 29.0, 29.0, Pattern::tiled(&texture, 0.0, 0.0).with_transform([1.0, 0.0, 0.0, 1.0, 2.0, 1.0]), 0, 1.0);
+        assert_eq!(image.image_data, moved.image_data);
+
+        // stretched image covers the rectangle, the pixels between are mixed, outside of it the edges continue
+        image.clear();
+        image.draw_rectangle(0.0, 0.0, 29.0, 29.0, Pattern::stretched(&texture, 5.0, 5.0, 12.0, 10.0), 0, 1.0);
+        assert_eq!(image.get_pixel(5, 5).unwrap(), [255, 0, 0]);
+        assert_eq!(image.get_pixel(12, 10).unwrap(), [0, 0, 255]);
+        assert_eq!(image.get_pixel(20, 20).unwrap(), [0, 0, 255]);
+        assert_eq!(image.get_pixel(0, 0).unwrap(), [255, 0, 0]);
+        assert_eq!(image.get_pixel(6, 5).unwrap(), [255, 64, 64]);
+
+        // scaled down pattern shows the average color of the covered area
+        let mut checkerboard: ImageRGB8 = ImageRGB8::new(64, 64, [0, 0, 0]);
+        for y in 0..64 {
+            for x in (y % 2..64).step_by(2) {
+                checkerboard.set_pixel(x, y, [255, 255, 255]);
+            }
+        }
+        for (x2, y2) in [(12.0, 12.0), (14.0, 10.0)] {
+            image.clear();
+            image.draw_rectangle(5.0, 5.0, x2, y2, Pattern::stretched(&checkerboard, 5.0, 5.0, x2, y2), 0, 1.0);
+            for y in 5..=y2 as usize {
+                for x in 5..=x2 as usize {
+                    let [red, _, _] = image.get_pixel(x, y).unwrap();
+                    assert!(red.abs_diff(128) <= 2, "{x} {y} {red}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn hatches() {
+        // only the lines of the hatch are drawn, one of them goes through the origin
+        let mut image: ImageRGB8 = ImageRGB8::new(30, 30, [0, 0, 0]);
+        image.draw_rectangle(0.0, 0.0, 29.0, 29.0, Hatch::new(HatchStyle::Horizontal, [255, 255, 255], 5.0, 0.0, 1.0), 0, 1.0);
+        for y in 0..30 {
+            assert_eq!(image.get_pixel(7, y).unwrap(), if y % 5 == 0 { [255; 3] } else { [0; 3] });
+        }
+        image.clear();
+        image.draw_rectangle(0.0, 0.0, 29.0, 29.0, Hatch::new(HatchStyle::Horizontal, [255, 255, 255], 5.0, std::f64::consts::PI / 2.0, 1.0), 0, 1.0);
+        assert_eq!(image.get_pixel(10, 3).unwrap(), [255; 3]);
+        assert_eq!(image.get_pixel(11, 3).unwrap(), [0; 3]);
+        image.clear();
+        image.draw_circle(15.0, 15.0, 12.0, Hatch::new(HatchStyle::Diagonal, [255, 255, 255], 8.0, 0.0, 1.0), 0, 1.0);
+        assert_eq!(image.get_pixel(15, 15).unwrap(), [255; 3]);
+        assert_eq!(image.get_pixel(17, 17).unwrap(), [255; 3]);
+        assert!(image.get_pixel(16, 15).unwrap()[0] < 128);
+        assert_eq!(image.get_pixel(29, 29).unwrap(), [0; 3]);
+        image.clear();
+        // lines of the cross-hatch are 16 pixels apart horizontally, so they go through whole pixels
+        image.draw_circle(15.0, 15.0, 12.0, Hatch::new(HatchStyle::CrossHatch, [255, 255, 255], 8.0 * std::f64::consts::SQRT_2, 0.0, 1.0), 0, 1.0);
+        assert_eq!(image.get_pixel(8, 8).unwrap(), [255; 3]);
+        assert_eq!(image.get_pixel(10, 6).unwrap(), [255; 3]);
+        assert_eq!(image.get_pixel(10, 8).unwrap(), [0; 3]);
+
+        // dots are placed on a grid, opacity is applied on top of the coverage of the hatch
+        image.clear();
+        image.draw_rectangle(0.0, 0.0, 29.0, 29.0, Hatch::new(HatchStyle::Dots, [255, 255, 255], 10.0, 0.0, 3.0), 0, 0.5);
+        assert_eq!(image.get_pixel(10, 20).unwrap(), [128; 3]);
+        assert_eq!(image.get_pixel(15, 15).unwrap(), [0; 3]);
+        // without spacing nothing is drawn
+        image.clear();
+        image.draw_rectangle(0.0, 0.0, 29.0, 29.0, Hatch::new(HatchStyle::Diagonal, [255, 255, 255], 0.0, 0.0, 1.0), 0, 1.0);
+        assert!(image.image_data.iter().all(|pixel| *pixel == [0; 3]));
+    }
+
+    #[test]
+    fn blend_modes() {
+        let background: [u8; 3] = [200, 100, 50];
+        let color: [u8; 3] = [128, 255, 0];
+        let expected: [(BlendMode, [u8; 3]); 11] = [
+            (BlendMode::Normal, [128, 255, 0]),
+            (BlendMode::Multiply, [100, 100, 0]),
+            (BlendMode::Screen, [228, 255, 50]),
+            (BlendMode::Overlay, [200, 200, 0]),
+            (BlendMode::Darken, [128, 100, 0]),
+            (BlendMode::Lighten, [200, 255, 50]),
+            (BlendMode::Difference, [72, 155, 50]),
+            (BlendMode::Additive, [255, 255, 50]),
+            (BlendMode::ColorDodge, [255, 255, 50]),
+            (BlendMode::ColorBurn, [145, 100, 0]),
+            (BlendMode::Xor, [72, 155, 50]),
+        ];
+        let mut image: ImageRGB8 = ImageRGB8::new(10, 10, background);
+        for (mode, color_expected) in expected {
+            image.clear();
+            image.set_blend_mode(mode);
+            image.draw_rectangle(0.0, 0.0, 9.0, 9.0, color, 0, 1.0);
+            assert_eq!(image.get_pixel(5, 5).unwrap(), color_expected, "{:?}", mode);
+        }
+
+        // opacity and coverage mix the blended color with the image
+        image.clear();
+        image.set_blend_mode(BlendMode::Multiply);
+        image.draw_rectangle(0.0, 0.0, 9.0, 9.0, [0, 0, 0], 0, 0.5);
+        assert_eq!(image.get_pixel(5, 5).unwrap(), [100, 50, 25]);
+        // drawing the same shape twice with XOR restores the image
+        image.clear();
+        image.set_blend_mode(BlendMode::Xor);
+        image.draw_circle(5.0, 5.0, 3.0, [255, 0, 255], 0, 1.0);
+        assert_eq!(image.get_pixel(5, 5).unwrap(), [55, 100, 205]);
+        image.draw_circle(5.0, 5.0, 3.0, [255, 0, 255], 0, 1.0);
+        assert_eq!(image.get_pixel(5, 5).unwrap(), background);
+
+        // on transparent pixels the color is drawn unchanged
+        let mut rgba: ImageRGBA8 = ImageRGBA8::new(10, 10, [0, 0, 0, 0]);
+        rgba.set_blend_mode(BlendMode::Multiply);
+        rgba.draw_rectangle(0.0, 0.0, 9.0, 9.0, [128, 255, 0, 255], 0, 1.0);
+        assert_eq!(rgba.get_pixel(5, 5).unwrap(), [128, 255, 0, 255]);
+    }
+
+    #[test]
+    fn linear_blending() {
+        use crate::pixel::Channel;
+
+        // lookup tables convert every channel value to linear light and back without changes
+        for value in 0..=u8::MAX {
+            assert_eq!(u8::from_linear(value.to_linear()), value);
+        }
+        for value in (0..=u16::MAX).step_by(7) {
+            assert_eq!(u16::from_linear(value.to_linear()), value);
+        }
+        assert_eq!(u8::from_linear(0.5), 188);
+
+        // half of white on black is half of the light, which is brighter than the half of the sRGB value
+        let mut image: ImageRGB8 = ImageRGB8::new(10, 10, [0, 0, 0]);
+        image.draw_rectangle(0.0, 0.0, 9.0, 9.0, [255, 255, 255], 0, 0.5);
+        assert_eq!(image.get_pixel(5, 5).unwrap(), [128; 3]);
+        image.clear();
+        image.set_linear_blending(true);
+        image.draw_rectangle(0.0, 0.0, 9.0, 9.0, [255, 255, 255], 0, 0.5);
+        assert_eq!(image.get_pixel(5, 5).unwrap(), [188; 3]);
+        // fully covered opaque colors are unchanged
+        image.draw_rectangle(0.0, 0.0, 9.0, 9.0, [10, 100, 200], 0, 1.0);
+        assert_eq!(image.get_pixel(5, 5).unwrap(), [10, 100, 200]);
+
+        // linear blending of 8 bit images matches the floating point image (which is always linear)
+        let mut linear: ImageRGB32F = ImageRGB32F::new(20, 20, [0.0, 0.0, 0.0]);
+        let mut srgb: ImageRGB8 = ImageRGB8::new(20, 20, [0, 0, 0]);
+        srgb.set_linear_blending(true);
+        linear.draw_circle(10.0, 10.0, 7.3, [1.0, 0.2, 0.0], 2, 0.7);
+        srgb.draw_circle(10.0, 10.0, 7.3, [1.0f32, 0.2, 0.0].convert::<[u8; 3]>(), 2, 0.7);
+        for (a, b) in linear.convert::<[u8; 3]>().image_data.iter().zip(srgb.image_data.iter()) {
+            for (channel_a, channel_b) in a.iter().zip(b.iter()) {
+                assert!((*channel_a as i32 - *channel_b as i32).abs() <= 1);
+            }
+        }
+
+        // alpha channel is blended normally
+        let mut rgba: ImageRGBA8 = ImageRGBA8::new(10, 10, [0, 0, 0, 0]);
+        rgba.set_linear_blending(true);
+        rgba.draw_rectangle(0.0, 0.0, 9.0, 9.0, [255, 0, 0, 255], 0, 0.5);
+        assert_eq!(rgba.get_pixel(5, 5).unwrap(), [255, 0, 0, 128]);
+    }
+
+    #[test]
+    fn paths() {
+        // paths made only of lines are the polygon through their points without the pixels on its edges and the same as the polyline
+        let points: [(f64, f64); 4] = [(3.0, 5.0), (25.0, 5.0), (25.0, 25.0), (15.0, 0.0)];
+        let mut path: Path = Path::new().move_to(points[0].0, points[0].1);
+        for &(x, y) in &points[1..] {
+            path = path.line_to(x, y);
+        }
+        let mut shape: ImageRGB8 = ImageRGB8::new(30, 30, [0, 0, 0]);
+        let mut image: ImageRGB8 = ImageRGB8::new(30, 30, [0, 0, 0]);
+        shape.draw_polygon(&points, FillRule::EvenOdd, [255, 255, 255], 0, 0.5);
+        image.fill_path(&path, FillRule::EvenOdd, [255, 255, 255], 0.5);
+        for (a, b) in image.image_data.iter().zip(shape.image_data.iter()) {
+            assert!(a[0] <= b[0]);
+        }
+        assert_eq!(image.get_pixel(10, 5).unwrap(), [64; 3]);
+        assert_eq!(shape.get_pixel(10, 5).unwrap(), [128; 3]);
+        assert_eq!(image.get_pixel(22, 10).unwrap(), [128; 3]);
+
+        // filled path covers exactly its area, so translucent paths sharing an edge don't overlap
+        image.clear();
+        let triangle: Path = Path::new().move_to(0.0, 0.0).line_to(30.0, 0.0).line_to(0.0, 30.0);
+        image.fill_path(&triangle, FillRule::NonZero, [255, 255, 255], 1.0);
+        let area: f64 = image.image_data.iter().map(|pixel| pixel[0] as f64 / 255.0).sum();
+        assert!((area - 450.0).abs() < 1.0);
+        image.clear();
+        image.fill_path(&Path::new().move_to(4.5, 4.5).line_to(14.5, 4.5).line_to(14.5, 24.5).line_to(4.5, 24.5), FillRule::NonZero, [255, 255, 255], 0.5);
+        image.fill_path(&Path::new().move_to(14.5, 4.5).line_to(24.5, 4.5).line_to(24.5, 24.5).line_to(14.5, 24.5), FillRule::NonZero, [255, 255, 255], 0.5);
+        for x in 5..25 {
+            for y in 5..25 {
+                assert_eq!(image.get_pixel(x, y).unwrap(), [128; 3]);
+            }
+        }
+        assert_eq!(image.get_pixel(4, 10).unwrap(), [0; 3]);
+
+        shape.clear();
+        image.clear();
+        shape.draw_polyline(&points, LineJoin::Round, LineCap::Square, [255, 255, 255], 3, 0.5);
+        image.stroke_path(&path, LineJoin::Round, LineCap::Square, [255, 255, 255], 3, 0.5);
+        assert_eq!(shape.image_data, image.image_data);
+
+        // curves are the same as the Bezier curves
+        shape.clear();
+        image.clear();
+        shape.draw_cubic_bezier((5.0, 5.0), (5.0, 25.0), (25.0, 25.0), (25.0, 5.0), [255, 255, 255], 2, 1.0);
+        image.stroke_path(&Path::new().move_to(5.0, 5.0).cubic_to(5.0, 25.0, 25.0, 25.0, 25.0, 5.0), LineJoin::Miter, LineCap::Butt, [255, 255, 255], 2, 1.0);
+        assert_eq!(shape.image_data, image.image_data);
+
+        // closed subpath is joined at its first point, inner subpath with opposite direction is a hole with the non-zero rule
+        image.clear();
+        let square: Path = Path::new().move_to(5.0, 5.0).line_to(25.0, 5.0).line_to(25.0, 25.0).line_to(5.0, 25.0).close();
+        image.stroke_path(&square, LineJoin::Miter, LineCap::Butt, [255, 255, 255], 3, 1.0);
+        assert_eq!(image.get_pixel(4, 4).unwrap(), [255; 3]);
+        image.clear();
+        image.fill_path(&square.move_to(10.0, 10.0).line_to(10.0, 20.0).line_to(20.0, 20.0).line_to(20.0, 10.0).close(), FillRule::NonZero, [255, 255, 255], 1.0);
+        assert_eq!(image.get_pixel(7, 15).unwrap(), [255; 3]);
+        assert_eq!(image.get_pixel(15, 15).unwrap(), [0; 3]);
+
+        // arc_to rounds the corner with a circle touching both lines (up to small differences of the anti-aliasing),
+        // the path is half a pixel bigger, as the rounded rectangle includes the pixels on its edges
+        let mut corner: ImageRGB8 = ImageRGB8::new(30, 30, [0, 0, 0]);
+        corner.fill_path(&Path::new().move_to(4.5, 4.5).line_to(25.5, 4.5).arc_to(25.5, 25.5, 4.5, 25.5, 10.5).line_to(4.5, 25.5).close(), FillRule::NonZero, [255, 255, 255], 1.0);
+        let mut rounded: ImageRGB8 = ImageRGB8::new(30, 30, [0, 0, 0]);
+        rounded.draw_rounded_rectangle(5.0, 5.0, 25.0, 25.0, [0.0, 10.0, 0.0, 0.0], [255, 255, 255], 0, 1.0);
+        for (a, b) in corner.image_data.iter().zip(rounded.image_data.iter()) {
+            assert!((a[0] as i32 - b[0] as i32).abs() <= 16);
+        }
+        assert_eq!(corner.get_pixel(25, 25).unwrap(), [0; 3]);
+    }
+
+    #[test]
+    fn dashes() {
+        // dashes and gaps alternate from the starting point, offset moves the pattern
+        let mut image: ImageRGB8 = ImageRGB8::new(30, 30, [0, 0, 0]);
+        image.draw_dashed_line(-0.5, 5.0, 29.5, 5.0, &[4.0, 4.0], 0.0, LineCap::Butt, [255, 255, 255], 1, 1.0);
+        image.draw_dashed_line(-0.5, 10.0, 29.5, 10.0, &[4.0, 4.0], 4.0, LineCap::Butt, [255, 255, 255], 1, 1.0);
+        for x in 0..30 {
+            let on: bool = x % 8 < 4;
+            assert_eq!(image.get_pixel(x, 5).unwrap(), if on { [255; 3] } else { [0; 3] });
+            assert_eq!(image.get_pixel(x, 10).unwrap(), if on { [0; 3] } else { [255; 3] });
+        }
+        // odd number of lengths is repeated, so dashes and gaps swap lengths
+        image.clear();
+        image.draw_dashed_polyline(&[(-0.5, 5.0), (29.5, 5.0)], &[3.0], 0.0, LineJoin::Miter, LineCap::Butt, [255, 255, 255], 1, 1.0);
+        assert_eq!(image.get_pixel(2, 5).unwrap(), [255; 3]);
+        assert_eq!(image.get_pixel(3, 5).unwrap(), [0; 3]);
+        assert_eq!(image.get_pixel(6, 5).unwrap(), [255; 3]);
+        // dashes of length 0 with round caps are dots
+        image.clear();
+        image.draw_dashed_line(5.0, 15.0, 25.0, 15.0, &[0.0, 10.0], 0.0, LineCap::Round, [255, 255, 255], 3, 1.0);
+        for x in [5, 15, 25] {
+            assert_eq!(image.get_pixel(x, 15).unwrap(), [255; 3]);
+        }
+        assert_eq!(image.get_pixel(10, 15).unwrap(), [0; 3]);
+        // without dashes the line is the same as the solid line
+        let mut solid: ImageRGB8 = ImageRGB8::new(30, 30, [0, 0, 0]);
+        image.clear();
+        image.draw_dashed_line(3.0, 4.0, 25.0, 21.0, &[], 0.0, LineCap::Butt, [255, 255, 255], 3, 1.0);
+        solid.draw_line(3.0, 4.0, 25.0, 21.0, [255, 255, 255], 3, 1.0);
+        assert_eq!(image.image_data, solid.image_data);
+
+        // dashed outlines cover only the pixels of the solid outlines
+        let mut dashed: ImageRGB8 = ImageRGB8::new(30, 30, [0, 0, 0]);
+        let mut solid: ImageRGB8 = ImageRGB8::new(30, 30, [0, 0, 0]);
+        dashed.draw_dashed_rectangle(3.0, 3.0, 26.0, 26.0, &[5.0, 3.0], 0.0, LineCap::Butt, [255, 255, 255], 3, 1.0);
+        solid.draw_rectangle(3.0, 3.0, 26.0, 26.0, [255, 255, 255], 3, 1.0);
+        for (a, b) in dashed.image_data.iter().zip(solid.image_data.iter()) {
+            assert!(a[0] <= b[0]);
+        }
+        assert_eq!(dashed.get_pixel(5, 3).unwrap(), [255; 3]);
+        assert_eq!(dashed.get_pixel(10, 3).unwrap(), [0; 3]);
+        // dash going over the starting corner is joined there like the other corners
+        assert_eq!(dashed.get_pixel(3, 3).unwrap(), [255; 3]);
+        dashed.clear();
+        dashed.draw_dashed_rectangle(3.0, 3.0, 26.0, 26.0, &[100.0, 1.0], 0.0, LineCap::Butt, [255, 255, 255], 3, 1.0);
+        assert_eq!(dashed.get_pixel(3, 3).unwrap(), [255; 3]);
+        dashed.clear();
+        let square: Path = Path::new().move_to(5.0, 5.0).line_to(25.0, 5.0).line_to(25.0, 25.0).line_to(5.0, 25.0).close();
+        dashed.stroke_path(&square.dashed(&[12.0, 3.0], 6.0), LineJoin::Miter, LineCap::Butt, [255, 255, 255], 3, 1.0);
+        assert_eq!(dashed.get_pixel(4, 4).unwrap(), [255; 3]);
+        dashed.clear();
+        solid.clear();
+        dashed.draw_dashed_circle(15.0, 15.0, 12.0, &[5.0, 3.0], 0.0, LineCap::Butt, [255, 255, 255], 3, 1.0);
+        solid.draw_circle(15.0, 15.0, 12.0, [255, 255, 255], 3, 1.0);
+        for (a, b) in dashed.image_data.iter().zip(solid.image_data.iter()) {
+            assert!(a[0] <= b[0].saturating_add(16));
+        }
+        assert_eq!(dashed.get_pixel(26, 16).unwrap(), [255; 3]);
+
+        // outlines without gaps (no dashes or gaps of length 0) are the same as the solid outlines
+        for dashes in [&[][..], &[4.0, 0.0][..]] {
+            dashed.clear();
+            solid.clear();
+            dashed.draw_dashed_rectangle(3.3, 2.6, 26.4, 25.8, dashes, 1.0, LineCap::Round, [255, 255, 255], 2, 0.7);
+            solid.draw_rectangle(3.3, 2.6, 26.4, 25.8, [255, 255, 255], 2, 0.7);
+            dashed.draw_dashed_circle(15.0, 15.0, 9.0, dashes, 1.0, LineCap::Round, [255, 0, 0], 3, 0.7);
+            solid.draw_circle(15.0, 15.0, 9.0, [255, 0, 0], 3, 0.7);
+            dashed.draw_dashed_ellipse(15.2, 14.7, 6.5, 3.1, dashes, 1.0, LineCap::Round, [0, 0, 255], 1, 0.7);
+            solid.draw_ellipse(15.2, 14.7, 6.5, 3.1, [0, 0, 255], 1, 0.7);
+            assert_eq!(dashed.image_data, solid.image_data);
+        }
+    }
+
+    #[test]
+    fn arrows() {
+        use std::f64::consts::PI;
+
+        // head and line are blended only once
+        let mut image: ImageRGB8 = ImageRGB8::new(30, 30, [0, 0, 0]);
+        image.draw_arrow(5.0, 15.0, 25.0, 15.0, ArrowHead::Filled, 8.0, PI / 4.0, false, [255, 255, 255], 1, 0.5);
+        assert_eq!(image.get_pixel(10, 15).unwrap(), [128; 3]);
+        assert_eq!(image.get_pixel(21, 15).unwrap(), [128; 3]);
+        assert_eq!(image.get_pixel(21, 17).unwrap(), [128; 3]);
+        assert_eq!(image.get_pixel(5, 17).unwrap(), [0; 3]);
+        // open head is only the two sides, double ended arrow has heads at both ends
+        image.clear();
+        image.draw_arrow(5.0, 15.0, 25.0, 15.0, ArrowHead::Open, 8.0, PI / 4.0, true, [255, 255, 255], 2, 1.0);
+        assert_eq!(image.get_pixel(21, 19).unwrap(), [255; 3]);
+        assert_eq!(image.get_pixel(9, 11).unwrap(), [255; 3]);
+        assert_eq!(image.get_pixel(19, 17).unwrap(), [0; 3]);
+        // barbed head is cut in at the back
+        image.clear();
+        image.draw_arrow(5.0, 15.0, 25.0, 15.0, ArrowHead::Barbed, 8.0, PI / 4.0, false, [255, 255, 255], 1, 1.0);
+        assert_eq!(image.get_pixel(22, 17).unwrap(), [255; 3]);
+        assert_eq!(image.get_pixel(20, 17).unwrap(), [0; 3]);
+
+        // arrow without a head is the same as the line
+        let mut line: ImageRGB8 = ImageRGB8::new(30, 30, [0, 0, 0]);
+        image.clear();
+        image.draw_arrow(3.0, 4.0, 25.0, 21.0, ArrowHead::Filled, 0.0, PI / 6.0, true, [255, 255, 255], 3, 1.0);
+        line.draw_line(3.0, 4.0, 25.0, 21.0, [255, 255, 255], 3, 1.0);
+        assert_eq!(image.image_data, line.image_data);
+    }
+
+    #[test]
+    fn arcs() {
+        use std::f64::consts::PI;
+
+        // full arc is the same as the outline of the circle, full chord and pie slice are the same as the ellipse
+        let mut arc: ImageRGB8 = ImageRGB8::new(41, 41, [0, 0, 0]);
+        let mut circle: ImageRGB8 = ImageRGB8::new(41, 41, [0, 0, 0]);
+        arc.draw_arc(20.0, 20.0, 15.0, 15.0, 1.0, 1.0 + 2.0 * PI, [255, 255, 255], 3, 1.0);
+        circle.draw_circle(20.0, 20.0, 15.0, [255, 255, 255], 3, 1.0);
+        assert_eq!(arc.image_data, circle.image_data);
+        arc.clear();
+        circle.clear();
+        arc.draw_chord(20.5, 19.3, 17.0, 11.2, 1.0, 1.0 + 2.0 * PI, [255, 255, 255], 0, 1.0);
+        circle.draw_ellipse(20.5, 19.3, 17.0, 11.2, [255, 255, 255], 0, 1.0);
+        assert_eq!(arc.image_data, circle.image_data);
+        arc.clear();
+        arc.draw_pie(20.5, 19.3, 17.0, 11.2, 1.0, 1.0 + 2.0 * PI, [255, 255, 255], 0, 1.0);
+        assert_eq!(arc.image_data, circle.image_data);
+        // half of the arc is the upper half of the outline, cut exactly through the center
+        arc.clear();
+        circle.clear();
+        arc.draw_arc(20.0, 20.0, 15.0, 15.0, 0.0, PI, [255, 255, 255], 3, 1.0);
+        circle.draw_circle(20.0, 20.0, 15.0, [255, 255, 255], 3, 1.0);
+        for x in 0..41 {
+            for y in 0..41 {
+                let expected: [u8; 3] = match y {
+                    21.. => circle.get_pixel(x, y).unwrap(),
+                    20 => circle.get_pixel(x, y).unwrap().map(|channel| (channel as f64 / 2.0).round() as u8),
+                    _ => [0; 3],
+                };
+                assert!((arc.get_pixel(x, y).unwrap()[0] as i32 - expected[0] as i32).abs() <= 1);
+            }
+        }
+
+        // quarter of the outline of the ellipse, going counterclockwise from the right to the top
+        let mut image: ImageRGB8 = ImageRGB8::new(41, 41, [0, 0, 0]);
+        image.draw_arc(20.0, 20.0, 18.0, 10.0, 0.0, PI / 2.0, [255, 255, 255], 2, 1.0);
+        assert_eq!(image.get_pixel(35, 25).unwrap(), [255; 3]);
+        assert_eq!(image.get_pixel(3, 20).unwrap(), [0; 3]);
+        assert_eq!(image.get_pixel(20, 10).unwrap(), [0; 3]);
+        // end angle smaller than the start angle goes over 0
+        image.clear();
+        image.draw_arc(20.0, 20.0, 18.0, 10.0, 1.5 * PI, 0.5 * PI, [255, 255, 255], 2, 1.0);
+        assert_eq!(image.get_pixel(37, 20).unwrap(), [255; 3]);
+        assert_eq!(image.get_pixel(2, 20).unwrap(), [0; 3]);
+
+        // pie slice covers the center, chord doesn't
+        image.clear();
+        image.draw_pie(20.0, 20.0, 15.0, 15.0, 0.25 * PI, 0.75 * PI, [255, 255, 255], 0, 1.0);
+        assert_eq!(image.get_pixel(20, 22).unwrap(), [255; 3]);
+        assert_eq!(image.get_pixel(20, 34).unwrap(), [255; 3]);
+        assert_eq!(image.get_pixel(30, 22).unwrap(), [0; 3]);
+        image.clear();
+        image.draw_chord(20.0, 20.0, 15.0, 15.0, 0.25 * PI, 0.75 * PI, [255, 255, 255], 0, 1.0);
+        assert_eq!(image.get_pixel(20, 22).unwrap(), [0; 3]);
+        assert_eq!(image.get_pixel(20, 34).unwrap(), [255; 3]);
+    }
+
+    #[test]
+    fn rounded_rectangle() {
+        // sharp corners give the same result as the rectangle, fully rounded square gives the same result as the circle
+        let mut image: ImageRGB8 = ImageRGB8::new(30, 30, [0, 0, 0]);
+        let mut expected: ImageRGB8 = ImageRGB8::new(30, 30, [0, 0, 0]);
+        image.draw_rounded_rectangle(3.0, 4.0, 25.0, 20.0, [0.0; 4], [255, 0, 0], 3, 0.8);
+        expected.draw_rectangle(3.0, 4.0, 25.0, 20.0, [255, 0, 0], 3, 0.8);
+        image.draw_rounded_rectangle(5.5, 5.5, 24.5, 24.5, [9.5; 4], [0, 255, 0], 2, 1.0);
+        expected.draw_circle(15.0, 15.0, 9.5, [0, 255, 0], 2, 1.0);
+        assert_eq!(image.image_data, expected.image_data);
+
+        // every corner has its own radius
+        image.clear();
+        image.draw_rounded_rectangle(25.0, 25.0, 4.0, 4.0, [0.0, 10.0, 0.0, 5.0], [255, 255, 255], 0, 1.0);
+        assert_eq!(image.get_pixel(4, 25).unwrap(), [255; 3]);
+        assert_eq!(image.get_pixel(25, 4).unwrap(), [255; 3]);
+        assert_eq!(image.get_pixel(25, 25).unwrap(), [0; 3]);
+        assert_eq!(image.get_pixel(4, 4).unwrap(), [0; 3]);
+        assert_eq!(image.get_pixel(23, 23).unwrap(), [0; 3]);
+        assert_eq!(image.get_pixel(6, 6).unwrap(), [255; 3]);
+    }
+
+    #[test]
+    fn rotated_shapes() {
+        use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+        // shapes rotated by a right angle are the same as the shapes with swapped sides (axes)
+        let mut image: ImageRGB8 = ImageRGB8::new(40, 40, [0, 0, 0]);
+        let mut expected: ImageRGB8 = ImageRGB8::new(40, 40, [0, 0, 0]);
+        image.draw_rotated_rectangle(5.0, 15.0, 35.0, 25.0, FRAC_PI_2, [255, 0, 0], 3, 1.0);
+        expected.draw_rectangle(15.0, 5.0, 25.0, 35.0, [255, 0, 0], 3, 1.0);
+        image.draw_rotated_ellipse(20.0, 20.0, 12.0, 5.5, -FRAC_PI_2, [0, 255, 0], 2, 0.5);
+        expected.draw_ellipse(20.0, 20.0, 5.5, 12.0, [0, 255, 0], 2, 0.5);
+        assert_eq!(image.image_data, expected.image_data);
+
+        // diagonal ellipse
+        image.clear();
+        image.draw_rotated_ellipse(20.0, 20.0, 15.0, 3.0, FRAC_PI_4, [255, 255, 255], 0, 1.0);
+        assert_eq!(image.get_pixel(28, 28).unwrap(), [255; 3]);
+        assert_eq!(image.get_pixel(12, 12).unwrap(), [255; 3]);
+        assert_eq!(image.get_pixel(28, 12).unwrap(), [0; 3]);
+        assert_eq!(image.get_pixel(33, 20).unwrap(), [0; 3]);
+    }
+
+    #[test]
+    fn polygon_helpers() {
+        // triangle is the same as the polygon with its vertices
+        let mut image: ImageRGB8 = ImageRGB8::new(30, 30, [0, 0, 0]);
+        let mut expected: ImageRGB8 = ImageRGB8::new(30, 30, [0, 0, 0]);
+        image.draw_triangle(3.0, 3.0, 26.0, 8.5, 10.2, 27.0, [255, 255, 255], 2, 0.7);
+        expected.draw_polygon(&[(3.0, 3.0), (26.0, 8.5), (10.2, 27.0)], FillRule::NonZero, [255, 255, 255], 2, 0.7);
+        assert_eq!(image.image_data, expected.image_data);
+
+        // square rotated by 45 degrees has one side at the top
+        image.clear();
+        expected.clear();
+        image.draw_regular_polygon(15.0, 15.0, 10.0 * std::f64::consts::SQRT_2, 4, std::f64::consts::FRAC_PI_4, [255, 255, 255], 0, 1.0);
+        expected.draw_rectangle(5.0, 5.0, 25.0, 25.0, [255, 255, 255], 0, 1.0);
+        for (a, b) in image.image_data.iter().zip(expected.image_data.iter()) {
+            assert!((a[0] as i32 - b[0] as i32).abs() <= 1);
+        }
+
+        // star has its tips on the outer circle and the center is filled
+        image.clear();
+        image.draw_star(15.0, 15.0, 12.0, 5.0, 5, [255, 255, 255], 0, 1.0);
+        assert_eq!(image.get_pixel(15, 25).unwrap(), [255; 3]);
+        assert!(image.get_pixel(15, 28).unwrap()[0] > 0);
+        assert_eq!(image.get_pixel(15, 15).unwrap(), [255; 3]);
+        assert_eq!(image.get_pixel(15, 5).unwrap(), [0; 3]);
+    }
 }
@@ -0,0 +1,423 @@
+//! A module that contains the [Paint] enum, the gradients, the image patterns and the hatches, which describe the colors that shapes are drawn with.
+
+use std::f64::consts::PI;
+use crate::image::Image;
+use crate::pixel::{Channel, Pixel};
+
+
+/// Rule that decides the color of a gradient outside of the range between its first and its last stop.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Spread {
+    /// Colors of the first and the last stop continue forever
+    Pad,
+    /// Gradient starts again from the first stop
+    Repeat,
+    /// Gradient goes back and forth between the first and the last stop
+    Reflect,
+}
+
+impl Spread {
+    fn apply(&self, position: f64) -> f64 {
+        // maps the position along the gradient to the range [0.0, 1.0]
+        match self {
+            Spread::Pad => position.clamp(0.0, 1.0),
+            Spread::Repeat => position.rem_euclid(1.0),
+            Spread::Reflect => 1.0 - (position.rem_euclid(2.0) - 1.0).abs(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum GradientShape {
+    // lines perpendicular to the line from start to end have the same color
+    Linear { start: (f64, f64), end: (f64, f64) },
+    // circles around the center have the same color
+    Radial { center: (f64, f64), radius: f64 },
+    // directions from the center have the same color (counterclockwise from the start angle, the end is sweep radians further)
+    Conic { center: (f64, f64), start_angle: f64, sweep: f64 },
+}
+
+/// A gradient between colors, placed in the coordinates of the image (the gradient doesn't move with the shapes it is used for).
+/// The colors are given as stops, which are pairs of a position along the gradient (`0.0` is the start and `1.0` the end) and a color.
+/// Colors between the stops are mixed with [Pixel::mix()].
+/// ```rust
+/// use tinydraw::{Gradient, ImageRGB8, Spread};
+///
+/// let mut image: ImageRGB8 = ImageRGB8::new(100, 100, [0, 0, 0]);
+/// let gradient: Gradient<[u8; 3]> = Gradient::linear(0.0, 0.0, 99.0, 0.0, &[(0.0, [255, 0, 0]), (1.0, [0, 0, 255])], Spread::Pad);
+/// image.draw_circle(49.0, 49.0, 40.0, gradient, 0, 1.0);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Gradient<P: Pixel> {
+    shape: GradientShape,
+    stops: Vec<(f64, P)>,
+    spread: Spread,
+}
+
+impl<P: Pixel> Gradient<P> {
+    fn new(shape: GradientShape, stops: &[(f64, P)], spread: Spread) -> Self {
+        // stops are sorted by their position (stops at the same position keep their order, so the color can change sharply)
+        let mut stops: Vec<(f64, P)> = stops.to_vec();
+        stops.sort_by(|first, second| first.0.total_cmp(&second.0));
+        Self { shape, stops, spread }
+    }
+
+    pub fn linear(x1: f64, y1: f64, x2: f64, y2: f64, stops: &[(f64, P)], spread: Spread) -> Self {
+        //! Returns a new linear gradient, which goes from `x1`, `y1` (position `0.0`) to `x2`, `y2` (position `1.0`).
+        //! `stops` are the positions and colors of the gradient (without stops the gradient is transparent black).
+        //! `spread` defines the colors before the start and after the end of the gradient (see [Spread]).
+
+        Self::new(GradientShape::Linear { start: (x1, y1), end: (x2, y2) }, stops, spread)
+    }
+
+    pub fn radial(x: f64, y: f64, radius: f64, stops: &[(f64, P)], spread: Spread) -> Self {
+        //! Returns a new radial gradient, which goes from the center `x`, `y` (position `0.0`) to the circle with `radius` around it (position `1.0`).
+        //! `stops` are the positions and colors of the gradient (without stops the gradient is transparent black).
+        //! `spread` defines the colors outside of the circle (see [Spread]).
+
+        Self::new(GradientShape::Radial { center: (x, y), radius }, stops, spread)
+    }
+
+    pub fn conic(x: f64, y: f64, start_angle: f64, sweep: f64, stops: &[(f64, P)], spread: Spread) -> Self {
+        //! Returns a new conic (sweep) gradient, which goes counterclockwise around the center `x`, `y`,
+        //! starting at `start_angle` (in radians, position `0.0`) and ending `sweep` radians further (position `1.0`, use `2.0 * PI` for the full turn).
+        //! `stops` are the positions and colors of the gradient (without stops the gradient is transparent black).
+        //! `spread` defines the colors of the directions after the end of the gradient, up to the full turn (see [Spread]).
+
+        Self::new(GradientShape::Conic { center: (x, y), start_angle, sweep }, stops, spread)
+    }
+
+    pub fn color_at(&self, x: f64, y: f64) -> P {
+        //! Returns the color of the gradient at the point `x`, `y`.
+
+        let position: f64 = match self.shape {
+            GradientShape::Linear { start, end } => {
+                // projection of the point onto the line from start to end
+                let (dx, dy) = (end.0 - start.0, end.1 - start.1);
+                let length: f64 = dx.powi(2) + dy.powi(2);
+                if length == 0.0 { 0.0 } else { ((x - start.0) * dx + (y - start.1) * dy) / length }
+            },
+            GradientShape::Radial { center, radius } => {
+                if radius <= 0.0 { 1.0 } else { (x - center.0).hypot(y - center.1) / radius }
+            },
+            GradientShape::Conic { center, start_angle, sweep } => {
+                if sweep <= 0.0 { 1.0 } else { ((y - center.1).atan2(x - center.0) - start_angle).rem_euclid(2.0 * PI) / sweep }
+            },
+        };
+        let position: f64 = self.spread.apply(position);
+        if self.stops.is_empty() {
+            return P::zeroed()
+        }
+
+        // first stop after the position, the color is mixed from it and the stop before it
+        match self.stops.iter().position(|stop| stop.0 > position) {
+            Some(0) => self.stops[0].1,
+            Some(index) => {
+                let (start, start_color) = self.stops[index - 1];
+                let (end, end_color) = self.stops[index];
+                start_color.mix(end_color, (position - start) / (end - start))
+            },
+            None => self.stops[self.stops.len() - 1].1,
+        }
+    }
+}
+
+/// An image used as a paint, placed in the coordinates of the image that is drawn on (the pattern doesn't move with the shapes it is used for).
+/// Colors between the pixels of the pattern are mixed from the four closest pixels (bilinear interpolation) with [Pixel::mix()].
+/// A pattern that is scaled down takes its colors from copies of the image repeatedly scaled down to half size (mipmaps),
+/// so each pixel gets the average color of the area it covers instead of the color of a single pattern pixel.
+/// ```rust
+/// use tinydraw::{ImageRGB8, Pattern};
+///
+/// let mut texture: ImageRGB8 = ImageRGB8::new(8, 8, [255, 255, 255]);
+/// texture.draw_rectangle(0.0, 0.0, 3.0, 3.0, [0, 0, 0], 0, 1.0);
+/// let mut image: ImageRGB8 = ImageRGB8::new(100, 100, [0, 0, 0]);
+/// image.draw_circle(49.0, 49.0, 40.0, Pattern::tiled(&texture, 0.0, 0.0), 0, 1.0);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Pattern<P: Pixel> {
+    // width, height and pixels of the image followed by its copies scaled down to half size, the last one has a single pixel
+    levels: Vec<(usize, usize, Vec<P>)>,
+    // colors outside of the pattern (repeated for tiles, padded for stretched patterns)
+    spread: Spread,
+    // affine transformation [a, b, c, d, e, f] from the coordinates of the pattern pixels to the coordinates of the image,
+    // point x, y of the pattern is placed at a * x + c * y + e, b * x + d * y + f
+    transform: [f64; 6],
+}
+
+impl<P: Pixel> Pattern<P> {
+    fn new(image: &Image<P>, spread: Spread, transform: [f64; 6]) -> Self {
+        let mut levels: Vec<(usize, usize, Vec<P>)> = vec![(image.width, image.height, image.image_data.clone())];
+        loop {
+            let (width, height, pixels) = &levels[levels.len() - 1];
+            if pixels.is_empty() || (*width == 1 && *height == 1) {
+                break
+            }
+            let level: (usize, usize, Vec<P>) = downsample(*width, *height, pixels);
+            levels.push(level);
+        }
+        Self { levels, spread, transform }
+    }
+
+    pub fn tiled(image: &Image<P>, x: f64, y: f64) -> Self {
+        //! Returns a new pattern made of copies of the `image` placed next to each other, the bottom left pixel of one copy is at `x`, `y`.
+
+        Self::new(image, Spread::Repeat, [1.0, 0.0, 0.0, 1.0, x, y])
+    }
+
+    pub fn stretched(image: &Image<P>, x1: f64, y1: f64, x2: f64, y2: f64) -> Self {
+        //! Returns a new pattern made of the `image` stretched over the rectangle with the corners `x1`, `y1` and `x2`, `y2` (the pixels in the corners of the rectangle are the corners of the `image`).
+        //! Outside of the rectangle the pixels on the edges of the `image` continue.
+
+        // outer edges of the image pixels are placed on the outer edges of the rectangle pixels
+        let scale_x: f64 = ((x1 - x2).abs() + 1.0) / image.width as f64;
+        let scale_y: f64 = ((y1 - y2).abs() + 1.0) / image.height as f64;
+        let translate_x: f64 = x1.min(x2) - 0.5 + 0.5 * scale_x;
+        let translate_y: f64 = y1.min(y2) - 0.5 + 0.5 * scale_y;
+        Self::new(image, Spread::Pad, [scale_x, 0.0, 0.0, scale_y, translate_x, translate_y])
+    }
+
+    pub fn with_transform(mut self, transform: [f64; 6]) -> Self {
+        //! Returns the pattern moved by the affine `transform` `[a, b, c, d, e, f]`, which moves the point `x`, `y` to `a * x + c * y + e`, `b * x + d * y + f`.
+        //! For example `[cos, sin, -sin, cos, 0.0, 0.0]` rotates the pattern counterclockwise around `(0.0, 0.0)` by the angle with the given sine and cosine.
+        //! The transform is applied after the pattern is placed.
+
+        let [a, b, c, d, e, f] = transform;
+        let [a2, b2, c2, d2, e2, f2] = self.transform;
+        self.transform = [a * a2 + c * b2, b * a2 + d * b2, a * c2 + c * d2, b * c2 + d * d2, a * e2 + c * f2 + e, b * e2 + d * f2 + f];
+        self
+    }
+
+    fn pixel(&self, level: usize, x: f64, y: f64) -> P {
+        // returns the pixel of the pattern level at whole coordinates x, y, coordinates outside of the image are repeated or padded
+        let (width, height, pixels) = &self.levels[level];
+        let index = |value: f64, limit: usize| match self.spread {
+            Spread::Repeat => value.rem_euclid(limit as f64) as usize,
+            _ => value.clamp(0.0, limit as f64 - 1.0) as usize,
+        };
+        let (x, y) = (index(x, *width), index(y, *height));
+        pixels[width * (height - 1 - y) + x]
+    }
+
+    fn sample(&self, level: usize, u: f64, v: f64) -> P {
+        // mixes the four closest pixels of the pattern level, u, v are in the coordinates of the full size image
+        let (width, height) = (self.levels[0].0 as f64, self.levels[0].1 as f64);
+        let (level_width, level_height) = (self.levels[level].0 as f64, self.levels[level].1 as f64);
+        // outer edges of the image pixels stay in place
+        let u: f64 = (u + 0.5) * level_width / width - 0.5;
+        let v: f64 = (v + 0.5) * level_height / height - 0.5;
+        let (u0, v0) = (u.floor(), v.floor());
+        let bottom: P = self.pixel(level, u0, v0).mix(self.pixel(level, u0 + 1.0, v0), u - u0);
+        let top: P = self.pixel(level, u0, v0 + 1.0).mix(self.pixel(level, u0 + 1.0, v0 + 1.0), u - u0);
+        bottom.mix(top, v - v0)
+    }
+
+    pub fn color_at(&self, x: f64, y: f64) -> P {
+        //! Returns the color of the pattern at the point `x`, `y`.
+
+        let [a, b, c, d, e, f] = self.transform;
+        let determinant: f64 = a * d - b * c;
+        if self.levels[0].2.is_empty() || determinant == 0.0 {
+            return P::zeroed()
+        }
+        // point in the coordinates of the pattern pixels (inverse transform)
+        let (dx, dy) = (x - e, y - f);
+        let u: f64 = (d * dx - c * dy) / determinant;
+        let v: f64 = (a * dy - b * dx) / determinant;
+        // pattern pixels covered by one image pixel select the level, every level has half the pixels of the previous one
+        let scale: f64 = (d * d + b * b).sqrt().max((c * c + a * a).sqrt()) / determinant.abs();
+        let level: f64 = scale.log2().clamp(0.0, (self.levels.len() - 1) as f64);
+        let lower: f64 = level.floor();
+        let color: P = self.sample(lower as usize, u, v);
+        if level > lower {
+            color.mix(self.sample(lower as usize + 1, u, v), level - lower)
+        } else {
+            color
+        }
+    }
+}
+
+fn downsample<P: Pixel>(width: usize, height: usize, pixels: &[P]) -> (usize, usize, Vec<P>) {
+    // returns the image scaled down to half size, every new pixel is the average of the area it covers,
+    // colors are weighted by alpha like in Pixel::mix()
+    let (new_width, new_height) = ((width / 2).max(1), (height / 2).max(1));
+    let (scale_x, scale_y) = (width as f64 / new_width as f64, height as f64 / new_height as f64);
+    let alpha_index: Option<usize> = P::COLOR_TYPE.has_alpha().then(|| P::COLOR_TYPE.channels() - 1);
+    let mut new_pixels: Vec<P> = Vec::with_capacity(new_width * new_height);
+    for row in 0..new_height {
+        let (top, bottom) = (row as f64 * scale_y, (row + 1) as f64 * scale_y);
+        for column in 0..new_width {
+            let (left, right) = (column as f64 * scale_x, (column + 1) as f64 * scale_x);
+            let mut sums: [f64; 4] = [0.0; 4];
+            let mut color_weight: f64 = 0.0;
+            for y in top.floor() as usize..(bottom.ceil() as usize).min(height) {
+                let weight_y: f64 = bottom.min(y as f64 + 1.0) - top.max(y as f64);
+                for x in left.floor() as usize..(right.ceil() as usize).min(width) {
+                    let weight: f64 = weight_y * (right.min(x as f64 + 1.0) - left.max(x as f64));
+                    let pixel: P = pixels[width * y + x];
+                    let alpha: f64 = pixel.alpha();
+                    color_weight += weight * alpha;
+                    for (index, (sum, channel)) in sums.iter_mut().zip(pixel.channels()).enumerate() {
+                        *sum += if Some(index) == alpha_index { weight * channel.to_f64() } else { weight * alpha * channel.to_f64() };
+                    }
+                }
+            }
+            let mut pixel: P = P::zeroed();
+            for (index, (channel, sum)) in pixel.channels_mut().iter_mut().zip(sums).enumerate() {
+                *channel = if Some(index) == alpha_index {
+                    P::Channel::from_f64(sum / (scale_x * scale_y))
+                } else if color_weight == 0.0 {
+                    P::Channel::from_f64(0.0)
+                } else {
+                    P::Channel::from_f64(sum / color_weight)
+                };
+            }
+            new_pixels.push(pixel);
+        }
+    }
+    (new_width, new_height, new_pixels)
+}
+
+/// Shape of the lines of a hatch.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HatchStyle {
+    /// Parallel lines going up to the right (at 45 degrees)
+    Diagonal,
+    /// Diagonal lines going up to the right and up to the left
+    CrossHatch,
+    /// Parallel horizontal lines
+    Horizontal,
+    /// Dots on a square grid
+    Dots,
+}
+
+/// Lines or dots of one color repeated across the image, shapes filled with a hatch are covered only where the lines are.
+/// Hatches are placed in the coordinates of the image (one of the lines or dots goes through `(0.0, 0.0)`), so shapes filled with the same hatch line up.
+/// ```rust
+/// use tinydraw::{Hatch, HatchStyle, ImageRGB8};
+///
+/// let mut image: ImageRGB8 = ImageRGB8::new(100, 100, [255, 255, 255]);
+/// image.draw_circle(49.0, 49.0, 40.0, Hatch::new(HatchStyle::CrossHatch, [0, 0, 0], 6.0, 0.0, 1.0), 0, 1.0);
+/// image.draw_circle(49.0, 49.0, 40.0, [0, 0, 0], 1, 1.0);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Hatch<P: Pixel> {
+    style: HatchStyle,
+    color: P,
+    spacing: f64,
+    angle: f64,
+    line_width: f64,
+}
+
+impl<P: Pixel> Hatch<P> {
+    pub fn new(style: HatchStyle, color: P, spacing: f64, angle: f64, line_width: f64) -> Self {
+        //! Returns a new hatch. `style` defines the shape of the lines (see [HatchStyle]) and `color` their color.
+        //! `spacing` defines the distance between the centers of neighbouring lines or dots (if it isn't positive, nothing will be drawn).
+        //! `angle` (in radians) defines the counterclockwise rotation of the lines or the grid of dots.
+        //! `line_width` defines how thick the lines will be, or the diameter of the dots.
+
+        Self { style, color, spacing, angle, line_width }
+    }
+
+    fn line_coverage(&self, x: f64, y: f64, angle: f64) -> f64 {
+        // returns the percentage of the pixel at x, y covered by the parallel lines going in the direction of angle
+        // distance from the closest line is measured along the normal of the lines, and the pixel is treated as a segment of length 1 along it
+        let (sin, cos) = angle.sin_cos();
+        let position: f64 = y * cos - x * sin;
+        let distance: f64 = (position - self.spacing * (position / self.spacing).round()).abs();
+        let half_width: f64 = self.line_width / 2.0;
+        ((distance + 0.5).min(half_width) - (distance - 0.5).max(-half_width)).clamp(0.0, 1.0)
+    }
+
+    pub fn coverage_at(&self, x: f64, y: f64) -> f64 {
+        //! Returns the percentage of the pixel at `x`, `y` covered by the lines or dots of the hatch.
+
+        if self.spacing <= 0.0 || self.line_width <= 0.0 {
+            return 0.0
+        }
+        match self.style {
+            HatchStyle::Diagonal => self.line_coverage(x, y, self.angle + PI / 4.0),
+            HatchStyle::CrossHatch => {
+                // pixel is covered by one set of lines or the other
+                let first: f64 = self.line_coverage(x, y, self.angle + PI / 4.0);
+                let second: f64 = self.line_coverage(x, y, self.angle - PI / 4.0);
+                first + second - first * second
+            },
+            HatchStyle::Horizontal => self.line_coverage(x, y, self.angle),
+            HatchStyle::Dots => {
+                // position of the pixel in the grid rotated clockwise, so that the grid is axis aligned
+                let (sin, cos) = self.angle.sin_cos();
+                let (u, v) = (x * cos + y * sin, y * cos - x * sin);
+                let distance: f64 = (u - self.spacing * (u / self.spacing).round()).hypot(v - self.spacing * (v / self.spacing).round());
+                let radius: f64 = self.line_width / 2.0;
+                (radius - distance + 0.5).clamp(0.0, 1.0).min(PI * radius.powi(2))
+            },
+        }
+    }
+
+    pub fn color_at(&self, _x: f64, _y: f64) -> P {
+        //! Returns the color of the hatch, which is the same everywhere (see [Hatch::coverage_at()] for where it is drawn).
+
+        self.color
+    }
+}
+
+/// Colors that shapes are drawn with, either a single color or a color that changes across the image.
+/// All drawing methods accept a color (pixel) or a [Paint], and anything else that converts into it (like a [Gradient], a [Pattern] or a [Hatch]).
+#[derive(Clone, Debug, PartialEq)]
+pub enum Paint<P: Pixel> {
+    /// Single color everywhere
+    Solid(P),
+    /// Colors of a gradient
+    Gradient(Gradient<P>),
+    /// Colors of an image
+    Pattern(Pattern<P>),
+    /// Lines or dots of a single color, with nothing drawn between them
+    Hatch(Hatch<P>),
+}
+
+impl<P: Pixel> Paint<P> {
+    pub fn color_at(&self, x: f64, y: f64) -> P {
+        //! Returns the color of the paint at the point `x`, `y`.
+
+        match self {
+            Paint::Solid(color) => *color,
+            Paint::Gradient(gradient) => gradient.color_at(x, y),
+            Paint::Pattern(pattern) => pattern.color_at(x, y),
+            Paint::Hatch(hatch) => hatch.color_at(x, y),
+        }
+    }
+
+    pub fn coverage_at(&self, x: f64, y: f64) -> f64 {
+        //! Returns the percentage of the pixel at `x`, `y` covered by the paint, which is `1.0` everywhere except for hatches.
+
+        match self {
+            Paint::Hatch(hatch) => hatch.coverage_at(x, y),
+            _ => 1.0,
+        }
+    }
+}
+
+impl<P: Pixel> From<P> for Paint<P> {
+    fn from(color: P) -> Self {
+        Paint::Solid(color)
+    }
+}
+
+impl<P: Pixel> From<Gradient<P>> for Paint<P> {
+    fn from(gradient: Gradient<P>) -> Self {
+        Paint::Gradient(gradient)
+    }
+}
+
+impl<P: Pixel> From<Pattern<P>> for Paint<P> {
+    fn from(pattern: Pattern<P>) -> Self {
+        Paint::Pattern(pattern)
+    }
+}
+
+impl<P: Pixel> From<Hatch<P>> for Paint<P> {
+    fn from(hatch: Hatch<P>) -> Self {
+        Paint::Hatch(hatch)
+    }
+}
@@ -0,0 +1,395 @@
+//! A module that contains the [Path] struct, which describes shapes made of lines and curves.
+//! Curves are flattened to connected lines when they are added to the path, the lines are at most 0.01 pixels away from the curves.
+
+use std::f64::consts::PI;
+
+
+// maximal distance (in pixels) between a curve and the lines approximating it
+pub(crate) const TOLERANCE: f64 = 0.01;
+// maximal number of subdivisions of a curve while flattening it
+const MAX_SUBDIVISIONS: usize = 16;
+
+fn chord_distance(point: (f64, f64), start: (f64, f64), end: (f64, f64)) -> f64 {
+    // returns the distance of the point from the line through start and end (or from start if they are the same)
+    let length: f64 = (end.0 - start.0).hypot(end.1 - start.1);
+    if length == 0.0 {
+        (point.0 - start.0).hypot(point.1 - start.1)
+    } else {
+        ((end.0 - start.0) * (start.1 - point.1) - (start.0 - point.0) * (end.1 - start.1)).abs() / length
+    }
+}
+
+fn midpoint(a: (f64, f64), b: (f64, f64)) -> (f64, f64) {
+    ((a.0 + b.0) / 2.0, (a.1 + b.1) / 2.0)
+}
+
+fn flatten_quadratic(start: (f64, f64), control: (f64, f64), end: (f64, f64), points: &mut Vec<(f64, f64)>) {
+    // appends points of the quadratic Bezier curve (without the starting point) to points
+    // the curve is split in half (de Casteljau's algorithm) until the control point is close enough to the chord
+    fn subdivide(start: (f64, f64), control: (f64, f64), end: (f64, f64), depth: usize, points: &mut Vec<(f64, f64)>) {
+        // curve lies inside of the triangle of its points and is at most half as far from the chord as the control point
+        if depth == MAX_SUBDIVISIONS || chord_distance(control, start, end) <= 2.0 * TOLERANCE {
+            points.push(end);
+        } else {
+            let a: (f64, f64) = midpoint(start, control);
+            let b: (f64, f64) = midpoint(control, end);
+            let middle: (f64, f64) = midpoint(a, b);
+            subdivide(start, a, middle, depth + 1, points);
+            subdivide(middle, b, end, depth + 1, points);
+        }
+    }
+    subdivide(start, control, end, 0, points);
+}
+
+fn flatten_cubic(start: (f64, f64), control1: (f64, f64), control2: (f64, f64), end: (f64, f64), points: &mut Vec<(f64, f64)>) {
+    // appends points of the cubic Bezier curve (without the starting point) to points
+    // the curve is split in half (de Casteljau's algorithm) until both control points are close enough to the chord
+    fn subdivide(start: (f64, f64), control1: (f64, f64), control2: (f64, f64), end: (f64, f64), depth: usize, points: &mut Vec<(f64, f64)>) {
+        // curve is at most 3/4 as far from the chord as the farther control point
+        if depth == MAX_SUBDIVISIONS || chord_distance(control1, start, end).max(chord_distance(control2, start, end)) * 0.75 <= TOLERANCE {
+            points.push(end);
+        } else {
+            let a: (f64, f64) = midpoint(start, control1);
+            let b: (f64, f64) = midpoint(control1, control2);
+            let c: (f64, f64) = midpoint(control2, end);
+            let d: (f64, f64) = midpoint(a, b);
+            let e: (f64, f64) = midpoint(b, c);
+            let middle: (f64, f64) = midpoint(d, e);
+            subdivide(start, a, d, middle, depth + 1, points);
+            subdivide(middle, e, c, end, depth + 1, points);
+        }
+    }
+    subdivide(start, control1, control2, end, 0, points);
+}
+
+fn circle_step(radius: f64) -> f64 {
+    // returns the angle between points on the circle, so that the lines between them are at most TOLERANCE away from the circle
+    if radius > TOLERANCE {
+        2.0 * (1.0 - TOLERANCE / radius).acos()
+    } else {
+        PI / 4.0
+    }
+}
+
+pub(crate) fn arc_sweep(start_angle: f64, end_angle: f64) -> f64 {
+    // returns the counterclockwise angle from start_angle to end_angle, which is 2 * PI if they are a full turn (or more) apart
+    if (end_angle - start_angle).abs() >= 2.0 * PI {
+        2.0 * PI
+    } else {
+        (end_angle - start_angle).rem_euclid(2.0 * PI)
+    }
+}
+
+pub(crate) fn ellipse_points(x: f64, y: f64, horizontal_axis: f64, vertical_axis: f64, start_angle: f64, sweep: f64) -> Vec<(f64, f64)> {
+    // returns points on the ellipse (both ends included) from start_angle counterclockwise by sweep (at most 2 * PI)
+    // angles are the directions from the center, points are spaced so that the lines between them are at most TOLERANCE away from the ellipse
+    let (a, b) = (horizontal_axis, vertical_axis);
+    // point (a * cos(t), b * sin(t)) lies in the direction atan2(b * sin(t), a * cos(t)) from the center
+    let parameter = |angle: f64| (a * angle.sin()).atan2(b * angle.cos());
+    let start: f64 = parameter(start_angle);
+    let mut end: f64 = if sweep >= 2.0 * PI { start + 2.0 * PI } else { parameter(start_angle + sweep) };
+    while end < start {
+        end += 2.0 * PI;
+    }
+    let segments: usize = ((end - start) / circle_step(a.max(b))).ceil().max(1.0) as usize;
+    (0..(segments + 1)).map(|i| {
+        let t: f64 = start + (end - start) * i as f64 / segments as f64;
+        (x + a * t.cos(), y + b * t.sin())
+    }).collect()
+}
+
+pub(crate) fn has_gaps(dashes: &[f64]) -> bool {
+    // returns whether the dash pattern splits paths, it doesn't if it is empty, contains an invalid length or all of its gaps are 0.0
+    // (odd number of lengths is repeated twice, so every length is also a gap)
+    if dashes.is_empty() || dashes.iter().any(|length| !length.is_finite() || *length < 0.0) || dashes.iter().sum::<f64>() <= 0.0 {
+        return false
+    }
+    if dashes.len() % 2 == 1 {
+        dashes.iter().any(|&length| length > 0.0)
+    } else {
+        dashes.iter().skip(1).step_by(2).any(|&length| length > 0.0)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Subpath {
+    // connected points, the last point is connected to the first one if the subpath is closed
+    pub(crate) points: Vec<(f64, f64)>,
+    pub(crate) closed: bool,
+}
+
+/// A shape made of lines and curves, which can be filled with [Image::fill_path()](crate::Image::fill_path) or stroked with [Image::stroke_path()](crate::Image::stroke_path).
+/// A path consists of subpaths, each of them starts with [Path::move_to()] and can be closed with [Path::close()].
+/// ```rust
+/// use tinydraw::Path;
+///
+/// // rectangle with the top side curved upwards
+/// let path: Path = Path::new()
+///     .move_to(10.0, 10.0)
+///     .line_to(50.0, 10.0)
+///     .line_to(50.0, 30.0)
+///     .quad_to(30.0, 50.0, 10.0, 30.0)
+///     .close();
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Path {
+    pub(crate) subpaths: Vec<Subpath>,
+}
+
+impl Path {
+    pub fn new() -> Self {
+        //! Returns a new empty [Path].
+
+        Self { subpaths: Vec::new() }
+    }
+
+    pub(crate) fn polygon(points: &[(f64, f64)]) -> Self {
+        // returns a path with one closed subpath through the points
+        Self { subpaths: vec![Subpath { points: points.to_vec(), closed: true }] }
+    }
+
+    pub(crate) fn polyline(points: &[(f64, f64)]) -> Self {
+        // returns a path with one open subpath through the points
+        Self { subpaths: vec![Subpath { points: points.to_vec(), closed: false }] }
+    }
+
+    fn current_point(&self) -> Option<(f64, f64)> {
+        // returns the point where the next line or curve starts (the first point of the last subpath if it is closed)
+        self.subpaths.last().map(|subpath| if subpath.closed {
+            subpath.points[0]
+        } else {
+            subpath.points[subpath.points.len() - 1]
+        })
+    }
+
+    fn push_point(&mut self, point: (f64, f64)) {
+        // connects the point to the current point, after a closed subpath a new one is started at its first point
+        match self.subpaths.last() {
+            Some(subpath) if subpath.closed => {
+                let start: (f64, f64) = subpath.points[0];
+                self.subpaths.push(Subpath { points: vec![start, point], closed: false });
+            },
+            Some(_) => self.subpaths.last_mut().expect("This shouldn't fail!").points.push(point),
+            None => self.subpaths.push(Subpath { points: vec![point], closed: false }),
+        }
+    }
+
+    pub fn move_to(mut self, x: f64, y: f64) -> Self {
+        //! Starts a new subpath at `x`, `y`.
+
+        self.subpaths.push(Subpath { points: vec![(x, y)], closed: false });
+        self
+    }
+
+    pub fn line_to(mut self, x: f64, y: f64) -> Self {
+        //! Adds a straight line from the current point to `x`, `y`.
+        //! If the path is empty, a new subpath is started at `x`, `y`.
+
+        self.push_point((x, y));
+        self
+    }
+
+    pub fn quad_to(mut self, control_x: f64, control_y: f64, x: f64, y: f64) -> Self {
+        //! Adds a quadratic Bezier curve from the current point to `x`, `y` with the control point `control_x`, `control_y`.
+        //! If the path is empty, a new subpath is started at the control point.
+
+        let start: (f64, f64) = self.current_point().unwrap_or((control_x, control_y));
+        let mut points: Vec<(f64, f64)> = vec![start];
+        flatten_quadratic(start, (control_x, control_y), (x, y), &mut points);
+        points.into_iter().for_each(|point| self.push_point(point));
+        self
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn cubic_to(mut self, control1_x: f64, control1_y: f64, control2_x: f64, control2_y: f64, x: f64, y: f64) -> Self {
+        //! Adds a cubic Bezier curve from the current point to `x`, `y` with the control points `control1_x`, `control1_y` and `control2_x`, `control2_y`.
+        //! If the path is empty, a new subpath is started at the first control point.
+
+        let start: (f64, f64) = self.current_point().unwrap_or((control1_x, control1_y));
+        let mut points: Vec<(f64, f64)> = vec![start];
+        flatten_cubic(start, (control1_x, control1_y), (control2_x, control2_y), (x, y), &mut points);
+        points.into_iter().for_each(|point| self.push_point(point));
+        self
+    }
+
+    pub fn spline_to(mut self, points: &[(f64, f64)]) -> Self {
+        //! Adds a smooth curve from the current point through all `points` (Catmull-Rom spline).
+        //! The direction of the curve at every point is parallel to the line between the neighbouring points, at the ends the curve points to the neighbouring point.
+        //! If the path is empty, a new subpath is started at the first point.
+
+        let mut through: Vec<(f64, f64)> = match self.current_point() {
+            Some(point) => vec![point],
+            None => Vec::new(),
+        };
+        through.extend_from_slice(points);
+        if through.is_empty() {
+            return self
+        }
+        if self.subpaths.is_empty() {
+            self = self.move_to(through[0].0, through[0].1);
+        }
+        // every part of the spline is a cubic Bezier curve, its control points are a sixth of the distance between the neighbours away from the ends
+        let count: usize = through.len();
+        for i in 0..(count - 1) {
+            let previous: (f64, f64) = through[i.saturating_sub(1)];
+            let (start, end) = (through[i], through[i + 1]);
+            let next: (f64, f64) = through[(i + 2).min(count - 1)];
+            let control1: (f64, f64) = (start.0 + (end.0 - previous.0) / 6.0, start.1 + (end.1 - previous.1) / 6.0);
+            let control2: (f64, f64) = (end.0 - (next.0 - start.0) / 6.0, end.1 - (next.1 - start.1) / 6.0);
+            self = self.cubic_to(control1.0, control1.1, control2.0, control2.1, end.0, end.1);
+        }
+        self
+    }
+
+    pub fn arc_to(mut self, x1: f64, y1: f64, x2: f64, y2: f64, radius: f64) -> Self {
+        //! Adds a circular arc with the given `radius` that touches the line from the current point to `x1`, `y1` and the line from `x1`, `y1` to `x2`, `y2`
+        //! (the corner at `x1`, `y1` is rounded), the current point is connected to the start of the arc with a straight line.
+        //! If the points are on the same line or `radius` is `0.0`, a straight line to `x1`, `y1` is added instead.
+        //! If the path is empty, a new subpath is started at `x1`, `y1`.
+
+        let start: (f64, f64) = match self.current_point() {
+            Some(point) => point,
+            None => return self.move_to(x1, y1),
+        };
+        let corner: (f64, f64) = (x1, y1);
+        let length1: f64 = (start.0 - x1).hypot(start.1 - y1);
+        let length2: f64 = (x2 - x1).hypot(y2 - y1);
+        let cross: f64 = (start.0 - x1) * (y2 - y1) - (start.1 - y1) * (x2 - x1);
+        if radius <= 0.0 || length1 == 0.0 || length2 == 0.0 || cross == 0.0 {
+            self.push_point(corner);
+            return self
+        }
+        // unit directions from the corner to the neighbouring points and the angle between them
+        let direction1: (f64, f64) = ((start.0 - x1) / length1, (start.1 - y1) / length1);
+        let direction2: (f64, f64) = ((x2 - x1) / length2, (y2 - y1) / length2);
+        let angle: f64 = (direction1.0 * direction2.0 + direction1.1 * direction2.1).clamp(-1.0, 1.0).acos();
+        // arc touches the lines at tangent_distance from the corner, its center lies on the bisector of the angle
+        let tangent_distance: f64 = radius / (angle / 2.0).tan();
+        let bisector: (f64, f64) = (direction1.0 + direction2.0, direction1.1 + direction2.1);
+        let bisector_length: f64 = bisector.0.hypot(bisector.1);
+        let center_distance: f64 = radius / (angle / 2.0).sin();
+        let center: (f64, f64) = (x1 + bisector.0 / bisector_length * center_distance, y1 + bisector.1 / bisector_length * center_distance);
+        let tangent1: (f64, f64) = (x1 + direction1.0 * tangent_distance, y1 + direction1.1 * tangent_distance);
+        let tangent2: (f64, f64) = (x1 + direction2.0 * tangent_distance, y1 + direction2.1 * tangent_distance);
+
+        // arc goes the short way around the center (clockwise if the lines turn right)
+        let start_angle: f64 = (tangent1.1 - center.1).atan2(tangent1.0 - center.0);
+        let sweep: f64 = (PI - angle).copysign(-cross);
+        let segments: usize = (sweep.abs() / circle_step(radius)).ceil().max(1.0) as usize;
+        self.push_point(tangent1);
+        for i in 1..segments {
+            let point_angle: f64 = start_angle + sweep * i as f64 / segments as f64;
+            self.push_point((center.0 + radius * point_angle.cos(), center.1 + radius * point_angle.sin()));
+        }
+        self.push_point(tangent2);
+        self
+    }
+
+    pub(crate) fn rectangle_outline(x1: f64, y1: f64, x2: f64, y2: f64, inset: f64) -> Self {
+        // returns a closed path around the rectangle moved inwards by inset, starting at the bottom left corner and going counterclockwise
+        let inset: f64 = inset.min((x1 - x2).abs().min((y1 - y2).abs()) / 2.0);
+        let (left, right) = (x1.min(x2) + inset, x1.max(x2) - inset);
+        let (bottom, top) = (y1.min(y2) + inset, y1.max(y2) - inset);
+        Self::polygon(&[(left, bottom), (right, bottom), (right, top), (left, top)])
+    }
+
+    pub(crate) fn ellipse_outline(x: f64, y: f64, horizontal_axis: f64, vertical_axis: f64, inset: f64) -> Self {
+        // returns a closed path around the ellipse moved inwards by inset (along the normals of the ellipse), starting at the right and going counterclockwise
+        let inset: f64 = inset.min(horizontal_axis.min(vertical_axis));
+        let points: Vec<(f64, f64)> = ellipse_points(0.0, 0.0, horizontal_axis, vertical_axis, 0.0, 2.0 * PI);
+        // last point is the same as the first one
+        Self::polygon(&points[..(points.len() - 1)].iter().map(|&(px, py)| {
+            let (nx, ny) = (px / horizontal_axis.powi(2), py / vertical_axis.powi(2));
+            let length: f64 = nx.hypot(ny);
+            (x + px - nx / length * inset, y + py - ny / length * inset)
+        }).collect::<Vec<(f64, f64)>>())
+    }
+
+    pub fn close(mut self) -> Self {
+        //! Closes the current subpath by connecting its last point with its first point.
+        //! Next line or curve starts a new subpath at the first point of the closed subpath.
+
+        if let Some(subpath) = self.subpaths.last_mut() {
+            subpath.closed = true;
+        }
+        self
+    }
+
+    pub fn dashed(&self, dashes: &[f64], offset: f64) -> Self {
+        //! Returns the path split into dashes, which can be drawn with [Image::stroke_path()](crate::Image::stroke_path).
+        //! `dashes` are the lengths (in pixels) of the dashes and the gaps between them, alternating and starting with a dash (odd number of lengths is repeated twice).
+        //! `offset` defines how far into the pattern every subpath starts.
+        //! On a closed subpath the dash going over its first point is one dash, so it is joined there like at the other points.
+        //! Dashes with the length of `0.0` are single points, which are drawn as dots with [LineCap::Round](crate::LineCap::Round).
+        //! If `dashes` is empty, contains a negative (or infinite) length or has no gaps (all gaps or all lengths are `0.0`), the path is returned unchanged.
+
+        if !has_gaps(dashes) {
+            return self.clone()
+        }
+        let pattern: Vec<f64> = if dashes.len() % 2 == 1 { dashes.repeat(2) } else { dashes.to_vec() };
+        let total: f64 = pattern.iter().sum();
+
+        let mut dashed: Self = Self::new();
+        for subpath in self.subpaths.iter().filter(|subpath| !subpath.points.is_empty()) {
+            let mut points: Vec<(f64, f64)> = subpath.points.clone();
+            if subpath.closed {
+                points.push(points[0]);
+            }
+            // find the part of the pattern where the subpath starts (even indices are dashes, odd are gaps)
+            let mut index: usize = 0;
+            let mut position: f64 = offset.rem_euclid(total);
+            while position > 0.0 && position >= pattern[index] {
+                position -= pattern[index];
+                index = (index + 1) % pattern.len();
+            }
+            let mut remaining: f64 = pattern[index] - position;
+            let mut dash: Option<Vec<(f64, f64)>> = if index.is_multiple_of(2) { Some(vec![points[0]]) } else { None };
+            // first dash of the subpath (if it starts at the first point), a closed subpath may end with a dash continuing it
+            let first_dash: Option<usize> = dash.as_ref().map(|_| dashed.subpaths.len());
+
+            for segment in points.windows(2) {
+                let (start, end) = (segment[0], segment[1]);
+                let length: f64 = (end.0 - start.0).hypot(end.1 - start.1);
+                if length == 0.0 {
+                    continue
+                }
+                // walk along the segment, ending and starting dashes where the pattern changes
+                let mut travelled: f64 = 0.0;
+                while travelled + remaining <= length {
+                    travelled += remaining;
+                    let point: (f64, f64) = (start.0 + (end.0 - start.0) * travelled / length, start.1 + (end.1 - start.1) * travelled / length);
+                    match dash.take() {
+                        Some(mut dash_points) => {
+                            dash_points.push(point);
+                            dashed.subpaths.push(Subpath { points: dash_points, closed: false });
+                        },
+                        None => dash = Some(vec![point]),
+                    }
+                    index = (index + 1) % pattern.len();
+                    remaining = pattern[index];
+                }
+                remaining -= length - travelled;
+                if let Some(dash_points) = dash.as_mut() {
+                    dash_points.push(end);
+                }
+            }
+            if let (Some(first), true) = (first_dash, subpath.closed && dash.is_some()) {
+                // dash going over the first point of a closed subpath is one dash, so it is joined there
+                let mut dash_points: Vec<(f64, f64)> = dash.take().expect("This shouldn't fail!");
+                match dashed.subpaths.get_mut(first) {
+                    Some(first_subpath) => {
+                        dash_points.extend_from_slice(&first_subpath.points[1..]);
+                        first_subpath.points = dash_points;
+                    },
+                    // pattern hasn't changed along the whole subpath, so it stays closed
+                    None => dashed.subpaths.push(subpath.clone()),
+                }
+            }
+            // dash that has just started at the end of the subpath is empty (unlike the dashes with length 0)
+            if let Some(dash_points) = dash.filter(|dash_points| pattern[index] == 0.0 || dash_points.iter().any(|&point| point != dash_points[0])) {
+                dashed.subpaths.push(Subpath { points: dash_points, closed: false });
+            }
+        }
+        dashed
+    }
+}
@@ -0,0 +1,527 @@
+//! A module that contains the [Pixel] trait and its implementations for all supported pixel formats.
+//!
+//! Pixels are arrays of channels, color channels are followed by the alpha channel (if the pixel format has one).
+//! Alpha is always straight (not premultiplied).
+//! Integer channels hold sRGB encoded values, while floating point channels hold linear light values,
+//! so colors are converted between sRGB and linear light when converting between integer and floating point pixels.
+//!
+//! | Pixel      | Format                                    |
+//! |------------|-------------------------------------------|
+//! | `[u8; 1]`  | Gray, bit depth of 8                      |
+//! | `[u16; 1]` | Gray, bit depth of 16                     |
+//! | `[u8; 2]`  | Gray + alpha, bit depth of 8              |
+//! | `[u8; 3]`  | RGB, bit depth of 8                       |
+//! | `[u8; 4]`  | RGBA, bit depth of 8                      |
+//! | `[u16; 2]` | Gray + alpha, bit depth of 16             |
+//! | `[u16; 3]` | RGB, bit depth of 16                      |
+//! | `[u16; 4]` | RGBA, bit depth of 16                     |
+//! | `[f32; 3]` | RGB, 32 bit floating point (linear light) |
+
+use std::fmt::Debug;
+use std::slice;
+use std::sync::OnceLock;
+use bytemuck::{Pod, cast_slice, cast_slice_mut};
+
+
+pub(crate) fn srgb_to_linear(value: f64) -> f64 {
+    // converts an sRGB encoded value to linear light (1.0 is full intensity)
+    if value <= 0.04045 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+pub(crate) fn linear_to_srgb(value: f64) -> f64 {
+    // converts a linear light value to sRGB encoding (1.0 is full intensity)
+    if value <= 0.0031308 {
+        value * 12.92
+    } else {
+        1.055 * value.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+/// Color type of a pixel format.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColorType {
+    /// One gray channel
+    Gray,
+    /// Gray channel and alpha channel
+    GrayAlpha,
+    /// Red, green and blue channels
+    Rgb,
+    /// Red, green, blue and alpha channels
+    Rgba,
+}
+
+impl ColorType {
+    pub fn channels(&self) -> usize {
+        //! Returns the number of channels (including alpha channel).
+
+        match self {
+            ColorType::Gray => 1,
+            ColorType::GrayAlpha => 2,
+            ColorType::Rgb => 3,
+            ColorType::Rgba => 4,
+        }
+    }
+
+    pub fn has_alpha(&self) -> bool {
+        //! Returns `true` if the color type has an alpha channel (which is always the last channel).
+
+        matches!(self, ColorType::GrayAlpha | ColorType::Rgba)
+    }
+}
+
+struct SrgbTable {
+    // lookup table of the linear light values of all sRGB encoded integer channel values,
+    // and of the linear light values halfway between the neighbouring channel values (for rounding linear light values to channel values)
+    linear: Vec<f64>,
+    thresholds: Vec<f64>,
+}
+
+impl SrgbTable {
+    fn new(max: usize) -> Self {
+        let linear: Vec<f64> = (0..(max + 1)).map(|value| srgb_to_linear(value as f64 / max as f64)).collect();
+        let thresholds: Vec<f64> = (0..max).map(|value| srgb_to_linear((value as f64 + 0.5) / max as f64)).collect();
+        Self { linear, thresholds }
+    }
+
+    fn decode(&self, value: usize) -> f64 {
+        self.linear[value]
+    }
+
+    fn encode(&self, value: f64) -> usize {
+        // channel value is the number of thresholds below the linear light value (same as rounding the sRGB encoded value)
+        self.thresholds.partition_point(|&threshold| threshold < value)
+    }
+}
+
+static U8_TABLE: OnceLock<SrgbTable> = OnceLock::new();
+static U16_TABLE: OnceLock<SrgbTable> = OnceLock::new();
+
+/// Operation that mixes the color of a drawn shape with the color of the image under it, before the result is blended with the opacity and the coverage.
+/// Channels are treated as values between `0.0` and `1.0` (the alpha channel is always composited normally).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BlendMode {
+    /// Color of the shape replaces the color of the image
+    Normal,
+    /// Colors are multiplied (result is darker)
+    Multiply,
+    /// Inverted colors are multiplied and inverted back (result is lighter)
+    Screen,
+    /// Multiply where the image is dark and screen where it is light (contrast of the image is kept)
+    Overlay,
+    /// Darker of the colors
+    Darken,
+    /// Lighter of the colors
+    Lighten,
+    /// Absolute difference of the colors
+    Difference,
+    /// Sum of the colors
+    Additive,
+    /// Image is brightened by dividing it with the inverted color of the shape
+    ColorDodge,
+    /// Image is darkened by dividing its inverted color with the color of the shape
+    ColorBurn,
+    /// Bitwise exclusive or of the channel values (drawing the same shape twice restores the image)
+    Xor,
+}
+
+impl BlendMode {
+    fn apply(&self, backdrop: f64, source: f64) -> f64 {
+        // returns the channel value mixed from the channel value of the image (backdrop) and of the shape (source)
+        match self {
+            BlendMode::Normal => source,
+            BlendMode::Multiply => backdrop * source,
+            BlendMode::Screen => backdrop + source - backdrop * source,
+            BlendMode::Overlay => if backdrop <= 0.5 {
+                2.0 * backdrop * source
+            } else {
+                1.0 - 2.0 * (1.0 - backdrop) * (1.0 - source)
+            },
+            BlendMode::Darken => backdrop.min(source),
+            BlendMode::Lighten => backdrop.max(source),
+            BlendMode::Difference => (backdrop - source).abs(),
+            BlendMode::Additive => backdrop + source,
+            BlendMode::ColorDodge => if backdrop <= 0.0 {
+                0.0
+            } else if source >= 1.0 {
+                1.0
+            } else {
+                (backdrop / (1.0 - source)).min(1.0)
+            },
+            BlendMode::ColorBurn => if backdrop >= 1.0 {
+                1.0
+            } else if source <= 0.0 {
+                0.0
+            } else {
+                1.0 - ((1.0 - backdrop) / source).min(1.0)
+            },
+            BlendMode::Xor => {
+                // values are compared as 16 bit integers, which is exact for 8 bit channels too (8 bit value v is 257 * v in 16 bits)
+                let bits = |value: f64| (value.clamp(0.0, 1.0) * 65535.0).round() as u32;
+                (bits(backdrop) ^ bits(source)) as f64 / 65535.0
+            },
+        }
+    }
+}
+
+/// A trait implemented by numeric types of pixel channels (`u8`, `u16` and `f32`).
+pub trait Channel: Pod + PartialEq + Debug {
+    /// Value of a channel at full intensity (`1.0` for floating point channels).
+    const MAX: f64;
+    /// Number of bits of a channel.
+    const BITS: usize;
+    /// `true` if the channel holds linear light values, `false` if it holds sRGB encoded values.
+    const LINEAR: bool;
+
+    /// Returns the value of the channel as [f64].
+    fn to_f64(self) -> f64;
+
+    /// Returns the channel from an [f64] value. Integer channels are rounded and clamped to their range, floating point channels are not limited.
+    fn from_f64(value: f64) -> Self;
+
+    /// Returns the value of the channel in linear light, where `1.0` is full intensity (sRGB encoded channels are decoded).
+    fn to_linear(self) -> f64 {
+        if Self::LINEAR {
+            self.to_f64() / Self::MAX
+        } else {
+            srgb_to_linear(self.to_f64() / Self::MAX)
+        }
+    }
+
+    /// Returns the channel from a linear light value, where `1.0` is full intensity (sRGB encoded channels are encoded).
+    fn from_linear(value: f64) -> Self {
+        if Self::LINEAR {
+            Self::from_f64(value * Self::MAX)
+        } else {
+            Self::from_f64(linear_to_srgb(value) * Self::MAX)
+        }
+    }
+}
+
+impl Channel for u8 {
+    const MAX: f64 = u8::MAX as f64;
+    const BITS: usize = 8;
+    const LINEAR: bool = false;
+
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+
+    fn from_f64(value: f64) -> Self {
+        value.round().clamp(0.0, <Self as Channel>::MAX) as u8
+    }
+
+    fn to_linear(self) -> f64 {
+        U8_TABLE.get_or_init(|| SrgbTable::new(u8::MAX as usize)).decode(self as usize)
+    }
+
+    fn from_linear(value: f64) -> Self {
+        U8_TABLE.get_or_init(|| SrgbTable::new(u8::MAX as usize)).encode(value) as u8
+    }
+}
+
+impl Channel for u16 {
+    const MAX: f64 = u16::MAX as f64;
+    const BITS: usize = 16;
+    const LINEAR: bool = false;
+
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+
+    fn from_f64(value: f64) -> Self {
+        value.round().clamp(0.0, <Self as Channel>::MAX) as u16
+    }
+
+    fn to_linear(self) -> f64 {
+        U16_TABLE.get_or_init(|| SrgbTable::new(u16::MAX as usize)).decode(self as usize)
+    }
+
+    fn from_linear(value: f64) -> Self {
+        U16_TABLE.get_or_init(|| SrgbTable::new(u16::MAX as usize)).encode(value) as u16
+    }
+}
+
+impl Channel for f32 {
+    const MAX: f64 = 1.0;
+    const BITS: usize = 32;
+    const LINEAR: bool = true;
+
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+
+    fn from_f64(value: f64) -> Self {
+        value as f32
+    }
+}
+
+/// A trait implemented by all pixel formats, which allows drawing on images of any format.
+/// Only the associated type and constant need to be defined, everything else is implemented on top of them.
+pub trait Pixel: Pod + PartialEq + Debug {
+    /// Numeric type of the channels.
+    type Channel: Channel;
+    /// Color type of the pixel.
+    const COLOR_TYPE: ColorType;
+
+    fn channels(&self) -> &[Self::Channel] {
+        //! Returns the channels of the pixel.
+
+        cast_slice(slice::from_ref(self))
+    }
+
+    fn channels_mut(&mut self) -> &mut [Self::Channel] {
+        //! Returns the mutable channels of the pixel.
+
+        cast_slice_mut(slice::from_mut(self))
+    }
+
+    fn alpha(&self) -> f64 {
+        //! Returns the alpha of the pixel, `0.0` is fully transparent and `1.0` fully opaque.
+        //! Pixels without alpha channel are always fully opaque.
+
+        if Self::COLOR_TYPE.has_alpha() {
+            self.channels()[Self::COLOR_TYPE.channels() - 1].to_f64() / Self::Channel::MAX
+        } else {
+            1.0
+        }
+    }
+
+    fn blend(&mut self, color: Self, opacity: f64) {
+        //! Blends the `color` into the pixel with the given `opacity`.
+        //! Pixels with alpha channel are composited with source-over operator (alpha of `color` is multiplied with `opacity`).
+
+        let color_channels: usize = if Self::COLOR_TYPE.has_alpha() {
+            Self::COLOR_TYPE.channels() - 1
+        } else {
+            Self::COLOR_TYPE.channels()
+        };
+        if Self::COLOR_TYPE.has_alpha() {
+            // alpha = new_alpha + alpha * (1 - new_alpha)
+            // color = (new_color * new_alpha + color * alpha * (1 - new_alpha)) / (new_alpha + alpha * (1 - new_alpha))
+            let new_alpha: f64 = color.alpha() * opacity.min(1.0);
+            if new_alpha >= 1.0 {
+                *self = color;
+            } else {
+                let old_alpha: f64 = self.alpha();
+                let alpha: f64 = new_alpha + old_alpha * (1.0 - new_alpha);
+                if alpha > 0.0 {
+                    for (channel, new_channel) in self.channels_mut().iter_mut().zip(color.channels()).take(color_channels) {
+                        *channel = Self::Channel::from_f64((new_channel.to_f64() * new_alpha + channel.to_f64() * old_alpha * (1.0 - new_alpha)) / alpha);
+                    }
+                    self.channels_mut()[color_channels] = Self::Channel::from_f64(alpha * Self::Channel::MAX);
+                }
+            }
+        } else if opacity >= 1.0 {
+            *self = color;
+        } else {
+            // background color aware ===> color = color + (new_color - color) * color_percentage ===> color = color * (1 - color_percentage) + new_color * color_percentage
+            for (channel, new_channel) in self.channels_mut().iter_mut().zip(color.channels()) {
+                *channel = Self::Channel::from_f64(channel.to_f64() * (1.0 - opacity) + new_channel.to_f64() * opacity);
+            }
+        }
+    }
+
+    fn blend_with(&mut self, color: Self, opacity: f64, mode: BlendMode) {
+        //! Blends the `color` into the pixel with the given `opacity`, after it is mixed with the pixel by the blend `mode` (see [BlendMode]).
+        //! Where the pixel is transparent, the `color` is blended unchanged.
+
+        if mode == BlendMode::Normal {
+            return self.blend(color, opacity)
+        }
+        let color_channels: usize = if Self::COLOR_TYPE.has_alpha() {
+            Self::COLOR_TYPE.channels() - 1
+        } else {
+            Self::COLOR_TYPE.channels()
+        };
+        // mixed = color * (1 - alpha) + mode(pixel, color) * alpha
+        let alpha: f64 = self.alpha();
+        let mut mixed: Self = color;
+        for (channel, (old_channel, new_channel)) in mixed.channels_mut().iter_mut().zip(self.channels().iter().zip(color.channels())).take(color_channels) {
+            let (backdrop, source) = (old_channel.to_f64() / Self::Channel::MAX, new_channel.to_f64() / Self::Channel::MAX);
+            *channel = Self::Channel::from_f64((source * (1.0 - alpha) + mode.apply(backdrop, source) * alpha) * Self::Channel::MAX);
+        }
+        self.blend(mixed, opacity);
+    }
+
+    fn blend_linear(&mut self, color: Self, opacity: f64, mode: BlendMode) {
+        //! Same as [Pixel::blend_with()], but sRGB encoded color channels are converted to linear light before blending and back after it,
+        //! so that partially covered and translucent colors keep their brightness (lookup tables are used for the conversion of integer channels).
+        //! Floating point pixels already hold linear light and [BlendMode::Xor] works on the stored values, so they are blended with [Pixel::blend_with()].
+
+        if Self::Channel::LINEAR || mode == BlendMode::Xor {
+            return self.blend_with(color, opacity, mode)
+        }
+        let color_channels: usize = if Self::COLOR_TYPE.has_alpha() {
+            Self::COLOR_TYPE.channels() - 1
+        } else {
+            Self::COLOR_TYPE.channels()
+        };
+        // same as blending with blend_with, but with linear light values (pixels without alpha channel have alpha of 1.0)
+        let old_alpha: f64 = self.alpha();
+        let new_alpha: f64 = color.alpha() * opacity.clamp(0.0, 1.0);
+        let alpha: f64 = new_alpha + old_alpha * (1.0 - new_alpha);
+        if alpha <= 0.0 {
+            return
+        }
+        for index in 0..color_channels {
+            let backdrop: f64 = self.channels()[index].to_linear();
+            let source: f64 = color.channels()[index].to_linear();
+            let mixed: f64 = source * (1.0 - old_alpha) + mode.apply(backdrop, source) * old_alpha;
+            self.channels_mut()[index] = Self::Channel::from_linear((mixed * new_alpha + backdrop * old_alpha * (1.0 - new_alpha)) / alpha);
+        }
+        if Self::COLOR_TYPE.has_alpha() {
+            self.channels_mut()[color_channels] = Self::Channel::from_f64(alpha * Self::Channel::MAX);
+        }
+    }
+
+    fn to_rgba(&self) -> [f64; 4] {
+        //! Returns the pixel as sRGB encoded red, green, blue and alpha values, where `1.0` is full intensity.
+
+        let channel = |index: usize| self.channels()[index].to_f64() / Self::Channel::MAX;
+        let color = |index: usize| if Self::Channel::LINEAR {
+            linear_to_srgb(channel(index))
+        } else {
+            channel(index)
+        };
+        match Self::COLOR_TYPE {
+            ColorType::Gray => [color(0), color(0), color(0), 1.0],
+            ColorType::GrayAlpha => [color(0), color(0), color(0), channel(1)],
+            ColorType::Rgb => [color(0), color(1), color(2), 1.0],
+            ColorType::Rgba => [color(0), color(1), color(2), channel(3)],
+        }
+    }
+
+    fn from_rgba(rgba: [f64; 4]) -> Self {
+        //! Returns the pixel from sRGB encoded red, green, blue and alpha values, where `1.0` is full intensity.
+        //! Colors are converted to gray as luminance (Rec. 709), alpha is dropped if the pixel has no alpha channel.
+
+        let color = |value: f64| if Self::Channel::LINEAR {
+            srgb_to_linear(value)
+        } else {
+            value
+        };
+        let gray: f64 = 0.2126 * rgba[0] + 0.7152 * rgba[1] + 0.0722 * rgba[2];
+        let values: [f64; 4] = match Self::COLOR_TYPE {
+            ColorType::Gray => [color(gray), 0.0, 0.0, 0.0],
+            ColorType::GrayAlpha => [color(gray), rgba[3], 0.0, 0.0],
+            ColorType::Rgb => [color(rgba[0]), color(rgba[1]), color(rgba[2]), 0.0],
+            ColorType::Rgba => [color(rgba[0]), color(rgba[1]), color(rgba[2]), rgba[3]],
+        };
+        let mut pixel: Self = Self::zeroed();
+        for (channel, value) in pixel.channels_mut().iter_mut().zip(values) {
+            *channel = Self::Channel::from_f64(value * Self::Channel::MAX);
+        }
+        pixel
+    }
+
+    fn convert<Q: Pixel>(&self) -> Q {
+        //! Converts the pixel to another pixel format.
+
+        Q::from_rgba(self.to_rgba())
+    }
+
+    fn mix(&self, color: Self, amount: f64) -> Self {
+        //! Returns the color between the pixel (`amount` of `0.0`) and `color` (`amount` of `1.0`).
+        //! Colors of pixels with alpha channel are weighted by their alpha, so fully transparent colors don't change the color of the other pixel.
+
+        let amount: f64 = amount.clamp(0.0, 1.0);
+        let mut pixel: Self = *self;
+        if Self::COLOR_TYPE.has_alpha() {
+            let (alpha1, alpha2) = (self.alpha(), color.alpha());
+            let alpha: f64 = alpha1 * (1.0 - amount) + alpha2 * amount;
+            let color_channels: usize = Self::COLOR_TYPE.channels() - 1;
+            for (channel, new_channel) in pixel.channels_mut().iter_mut().zip(color.channels()).take(color_channels) {
+                *channel = if alpha == 0.0 {
+                    Self::Channel::from_f64(0.0)
+                } else {
+                    Self::Channel::from_f64((channel.to_f64() * alpha1 * (1.0 - amount) + new_channel.to_f64() * alpha2 * amount) / alpha)
+                };
+            }
+            pixel.channels_mut()[color_channels] = Self::Channel::from_f64(alpha * Self::Channel::MAX);
+        } else {
+            for (channel, new_channel) in pixel.channels_mut().iter_mut().zip(color.channels()) {
+                *channel = Self::Channel::from_f64(channel.to_f64() * (1.0 - amount) + new_channel.to_f64() * amount);
+            }
+        }
+        pixel
+    }
+
+    fn premultiplied(&self) -> Self {
+        //! Returns the pixel with color channels multiplied by alpha. Pixels without alpha channel are returned unchanged.
+
+        let mut pixel: Self = *self;
+        if Self::COLOR_TYPE.has_alpha() {
+            let alpha: f64 = self.alpha();
+            for channel in pixel.channels_mut().iter_mut().take(Self::COLOR_TYPE.channels() - 1) {
+                *channel = Self::Channel::from_f64(channel.to_f64() * alpha);
+            }
+        }
+        pixel
+    }
+
+    fn unpremultiplied(&self) -> Self {
+        //! Returns the pixel with color channels divided by alpha (reverse of [Pixel::premultiplied()]). Pixels without alpha channel are returned unchanged.
+
+        let mut pixel: Self = *self;
+        if Self::COLOR_TYPE.has_alpha() {
+            let alpha: f64 = self.alpha();
+            for channel in pixel.channels_mut().iter_mut().take(Self::COLOR_TYPE.channels() - 1) {
+                *channel = if alpha == 0.0 {
+                    Self::Channel::from_f64(0.0)
+                } else {
+                    Self::Channel::from_f64(channel.to_f64() / alpha)
+                };
+            }
+        }
+        pixel
+    }
+}
+
+impl Pixel for [u8; 1] {
+    type Channel = u8;
+    const COLOR_TYPE: ColorType = ColorType::Gray;
+}
+
+impl Pixel for [u16; 1] {
+    type Channel = u16;
+    const COLOR_TYPE: ColorType = ColorType::Gray;
+}
+
+impl Pixel for [u8; 2] {
+    type Channel = u8;
+    const COLOR_TYPE: ColorType = ColorType::GrayAlpha;
+}
+
+impl Pixel for [u8; 3] {
+    type Channel = u8;
+    const COLOR_TYPE: ColorType = ColorType::Rgb;
+}
+
+impl Pixel for [u8; 4] {
+    type Channel = u8;
+    const COLOR_TYPE: ColorType = ColorType::Rgba;
+}
+
+impl Pixel for [u16; 2] {
+    type Channel = u16;
+    const COLOR_TYPE: ColorType = ColorType::GrayAlpha;
+}
+
+impl Pixel for [u16; 3] {
+    type Channel = u16;
+    const COLOR_TYPE: ColorType = ColorType::Rgb;
+}
+
+impl Pixel for [u16; 4] {
+    type Channel = u16;
+    const COLOR_TYPE: ColorType = ColorType::Rgba;
+}
+
+impl Pixel for [f32; 3] {
+    type Channel = f32;
+    const COLOR_TYPE: ColorType = ColorType::Rgb;
+}
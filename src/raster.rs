@@ -0,0 +1,723 @@
+//! A module that contains the rasterization algorithms shared by all image types.
+//! Every algorithm calculates which pixels are covered by the shape and calls `plot` with the coordinates of each covered pixel
+//! and the percentage of it covered by the shape (`0.0 < coverage <= 1.0`). Pixels outside of the image are never plotted.
+
+use std::f64::consts::{FRAC_1_SQRT_2, PI};
+use crate::path::{arc_sweep, Path, TOLERANCE};
+use crate::style::{ArrowHead, FillRule, LineCap, LineJoin};
+
+
+// number of horizontal lines that sample every row of pixels in the scanline rasterizer
+const SUBSCANLINES: usize = 16;
+// miter joins longer than this many half widths of the stroke are beveled
+const MITER_LIMIT: f64 = 4.0;
+
+fn clip_range(lower: f64, upper: f64, limit: usize) -> Option<(usize, usize)> {
+    // returns the first and the last pixel (inclusive) between lower and upper, that is inside of the image (0..limit)
+    let first: f64 = lower.ceil().max(0.0);
+    let last: f64 = upper.floor().min(limit as f64 - 1.0);
+    if first <= last {
+        Some((first as usize, last as usize))
+    } else {
+        None
+    }
+}
+
+fn pixel_overlap(lower: f64, upper: f64, pixel: usize) -> f64 {
+    // returns the percentage of the pixel (which covers [pixel - 0.5, pixel + 0.5]) that lies between lower and upper
+    (upper.min(pixel as f64 + 0.5) - lower.max(pixel as f64 - 0.5)).clamp(0.0, 1.0)
+}
+
+fn push_span(spans: &mut Vec<(f64, f64)>, start: f64, end: f64) {
+    // adds the span to the sorted spans, merging it with the last span if they touch
+    if start < end {
+        match spans.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => spans.push((start, end)),
+        }
+    }
+}
+
+fn combine_spans(a: &[(f64, f64)], b: &[(f64, f64)], operation: fn(bool, bool) -> bool, events: &mut Vec<(f64, bool)>, spans: &mut Vec<(f64, f64)>) {
+    // combines two lists of sorted, disjoint spans into spans (events is only a reused buffer),
+    // operation decides if a point inside (or outside) of a and b is in the result
+    events.clear();
+    events.extend(a.iter().flat_map(|&(start, end)| [(start, true), (end, true)]));
+    events.extend(b.iter().flat_map(|&(start, end)| [(start, false), (end, false)]));
+    events.sort_by(|first, second| first.0.total_cmp(&second.0));
+
+    spans.clear();
+    let (mut in_a, mut in_b) = (false, false);
+    let mut start: f64 = 0.0;
+    for &(x, from_a) in events.iter() {
+        let inside_before: bool = operation(in_a, in_b);
+        if from_a {
+            in_a = !in_a;
+        } else {
+            in_b = !in_b;
+        }
+        let inside_after: bool = operation(in_a, in_b);
+        if !inside_before && inside_after {
+            start = x;
+        } else if inside_before && !inside_after {
+            push_span(spans, start, x);
+        }
+    }
+}
+
+fn unit_normal(start: (f64, f64), end: (f64, f64)) -> (f64, f64) {
+    // returns the unit vector perpendicular to the segment from start to end (pointing to its left side)
+    let length: f64 = (end.0 - start.0).hypot(end.1 - start.1);
+    ((start.1 - end.1) / length, (end.0 - start.0) / length)
+}
+
+fn circle_polygon(center: (f64, f64), radius: f64) -> Vec<(f64, f64)> {
+    // returns a polygon approximating the circle, its edges are at most TOLERANCE away from the circle
+    let sides: usize = if radius > TOLERANCE {
+        ((PI / (1.0 - TOLERANCE / radius).acos()).ceil() as usize).max(8)
+    } else {
+        8
+    };
+    (0..sides).map(|i| {
+        let angle: f64 = 2.0 * PI * i as f64 / sides as f64;
+        (center.0 + radius * angle.cos(), center.1 + radius * angle.sin())
+    }).collect()
+}
+
+fn ellipse_distance(x: f64, y: f64, horizontal_axis: f64, vertical_axis: f64) -> f64 {
+    // returns the signed distance of the point x, y (relative to the center of the ellipse) to the ellipse (negative inside of the ellipse)
+    // the closest point on the ellipse is found iteratively, by approximating the ellipse locally with a circle around its center of curvature
+    let (a, b) = (horizontal_axis, vertical_axis);
+    let (px, py) = (x.abs(), y.abs());  // ellipse is symmetric, so only the first quadrant is considered
+    if a == b {
+        return px.hypot(py) - a
+    }
+    let mut tx: f64 = FRAC_1_SQRT_2;
+    let mut ty: f64 = FRAC_1_SQRT_2;
+    for _ in 0..4 {
+        // center of curvature of the current closest point
+        let ex: f64 = (a.powi(2) - b.powi(2)) * tx.powi(3) / a;
+        let ey: f64 = (b.powi(2) - a.powi(2)) * ty.powi(3) / b;
+        let r: f64 = (a * tx - ex).hypot(b * ty - ey);
+        let q: f64 = (px - ex).hypot(py - ey);
+        if q < 1e-12 {
+            break
+        }
+        // project the point onto the circle of curvature and back onto the ellipse
+        tx = (((px - ex) * r / q + ex) / a).clamp(0.0, 1.0);
+        ty = (((py - ey) * r / q + ey) / b).clamp(0.0, 1.0);
+        let t: f64 = tx.hypot(ty);
+        tx /= t;
+        ty /= t;
+    }
+    let distance: f64 = (px - a * tx).hypot(py - b * ty);
+    if (px / a).powi(2) + (py / b).powi(2) < 1.0 {
+        -distance
+    } else {
+        distance
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn line(width: usize, height: usize, x1: f64, y1: f64, x2: f64, y2: f64, thickness: usize, plot: &mut impl FnMut(usize, usize, f64)) {
+    // line from x1, y1 to x2, y2, thickness is measured perpendicular to the line and centered on it, the ends are cut perpendicular to the line
+    let mut shape: Shape = Shape::new(FillRule::NonZero);
+    add_line(&mut shape, (x1, y1), (x2, y2), thickness as f64 / 2.0);
+    fill(width, height, &Region::Shape(shape), plot);
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn rectangle(width: usize, height: usize, x1: f64, y1: f64, x2: f64, y2: f64, thickness: usize, plot: &mut impl FnMut(usize, usize, f64)) {
+    // axis aligned rectangle with corners x1, y1 and x2, y2, filled if thickness is 0, otherwise thickness is added to the inside
+
+    // outer edges of the rectangle (pixels in the corners are fully covered, so the edges lie half a pixel outside of them)
+    let smaller_x: f64 = x1.min(x2) - 0.5;
+    let bigger_x: f64 = x1.max(x2) + 0.5;
+    let smaller_y: f64 = y1.min(y2) - 0.5;
+    let bigger_y: f64 = y1.max(y2) + 0.5;
+    // inner edges of the rectangle (thickness is added to the inside), inside of them nothing is drawn
+    let inner_x: (f64, f64) = (smaller_x + thickness as f64, bigger_x - thickness as f64);
+    let inner_y: (f64, f64) = (smaller_y + thickness as f64, bigger_y - thickness as f64);
+    let hollow: bool = (thickness != 0) && (inner_x.0 < inner_x.1) && (inner_y.0 < inner_y.1);
+
+    if let (Some((lower_x, upper_x)), Some((lower_y, upper_y))) = (clip_range(smaller_x.floor(), bigger_x.ceil(), width), clip_range(smaller_y.floor(), bigger_y.ceil(), height)) {
+        for y in lower_y..(upper_y + 1) {
+            for x in lower_x..(upper_x + 1) {
+                // percentage of the pixel inside of the outer edges minus percentage of the pixel inside of the inner edges
+                let mut coverage: f64 = pixel_overlap(smaller_x, bigger_x, x) * pixel_overlap(smaller_y, bigger_y, y);
+                if hollow {
+                    coverage -= pixel_overlap(inner_x.0, inner_x.1, x) * pixel_overlap(inner_y.0, inner_y.1, y);
+                }
+                if coverage > 0.0 {
+                    plot(x, y, coverage);
+                }
+            }
+        }
+    }
+}
+
+fn edge_coverage(distance: f64, thickness: usize) -> f64 {
+    // returns the percentage of the pixel covered by a shape, distance is the signed distance of the pixel center to the shape (negative inside of it),
+    // the outer edge lies half a pixel outside of the shape (so the pixels on the shape are fully covered), it is filled if thickness is 0,
+    // otherwise the inner edge lies thickness pixels inside of the outer edge
+    let outer_edge: f64 = 0.5;
+    let inner_edge: f64 = if thickness == 0 {
+        f64::NEG_INFINITY
+    } else {
+        0.5 - thickness as f64
+    };
+    // percentage of the pixel inside of the outer edge minus percentage of the pixel inside of the inner edge
+    (outer_edge - distance + 0.5).clamp(0.0, 1.0) - (inner_edge - distance + 0.5).clamp(0.0, 1.0)
+}
+
+fn plot_field(width: usize, height: usize, center: (f64, f64), extent: (f64, f64), plot: &mut impl FnMut(usize, usize, f64), coverage: impl Fn(f64, f64) -> f64) {
+    // plots the coverage of every pixel around the center (up to extent away in both directions, plus a pixel for the anti-aliasing),
+    // coverage gets the position of the pixel center relative to the center
+    if let (Some((lower_x, upper_x)), Some((lower_y, upper_y))) = (clip_range(center.0 - extent.0 - 1.0, center.0 + extent.0 + 1.0, width), clip_range(center.1 - extent.1 - 1.0, center.1 + extent.1 + 1.0, height)) {
+        for y in lower_y..(upper_y + 1) {
+            for x in lower_x..(upper_x + 1) {
+                let pixel_coverage: f64 = coverage(x as f64 - center.0, y as f64 - center.1);
+                if pixel_coverage > 0.0 {
+                    plot(x, y, pixel_coverage);
+                }
+            }
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn ellipse(width: usize, height: usize, x: f64, y: f64, horizontal_axis: f64, vertical_axis: f64, angle: f64, thickness: usize, plot: &mut impl FnMut(usize, usize, f64)) {
+    // ellipse with the given center and axes rotated counterclockwise by angle, filled if thickness is 0, otherwise a ring on the inside of the ellipse
+    // the outer edge lies half a pixel outside of the ellipse (so the pixels on the ellipse are fully covered), thickness is added to the inside
+    // percentage of every pixel covered by the shape is calculated from the distance of the pixel center to the ellipse
+
+    if (horizontal_axis <= 0.0) || (vertical_axis <= 0.0) {
+        return
+    }
+
+    // half of the width and the height of the rotated ellipse
+    let (sin, cos) = angle.sin_cos();
+    let extent_x: f64 = (horizontal_axis * cos).hypot(vertical_axis * sin);
+    let extent_y: f64 = (horizontal_axis * sin).hypot(vertical_axis * cos);
+
+    plot_field(width, height, (x, y), (extent_x, extent_y), plot, |dx, dy| {
+        // pixel center is rotated clockwise around the center of the ellipse, so the distance is measured to the unrotated ellipse
+        edge_coverage(ellipse_distance(dx * cos + dy * sin, dy * cos - dx * sin, horizontal_axis, vertical_axis), thickness)
+    });
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn rounded_rectangle(width: usize, height: usize, x1: f64, y1: f64, x2: f64, y2: f64, radii: [f64; 4], angle: f64, thickness: usize, plot: &mut impl FnMut(usize, usize, f64)) {
+    // rectangle with corners x1, y1 and x2, y2 and rounded corners (radii are top left, top right, bottom right and bottom left),
+    // rotated counterclockwise by angle around its center, filled if thickness is 0, otherwise thickness is added to the inside
+    // like the ellipse, percentage of every pixel covered by the shape is calculated from the distance of the pixel center to the rounded rectangle
+
+    let center: (f64, f64) = ((x1 + x2) / 2.0, (y1 + y2) / 2.0);
+    let half_size: (f64, f64) = ((x1 - x2).abs() / 2.0, (y1 - y2).abs() / 2.0);
+    // radii can't be bigger than half of the shorter side
+    let radii: [f64; 4] = radii.map(|radius| radius.clamp(0.0, half_size.0.min(half_size.1)));
+
+    // half of the width and the height of the rotated rectangle
+    let (sin, cos) = angle.sin_cos();
+    let extent_x: f64 = half_size.0 * cos.abs() + half_size.1 * sin.abs();
+    let extent_y: f64 = half_size.0 * sin.abs() + half_size.1 * cos.abs();
+
+    plot_field(width, height, center, (extent_x, extent_y), plot, |dx, dy| {
+        // pixel center is rotated clockwise around the center of the rectangle, so the distance is measured to the unrotated rectangle
+        let (px, py) = (dx * cos + dy * sin, dy * cos - dx * sin);
+        let radius: f64 = match (px < 0.0, py < 0.0) {
+            (true, false) => radii[0],
+            (false, false) => radii[1],
+            (false, true) => radii[2],
+            (true, true) => radii[3],
+        };
+        // signed distance to the rounded rectangle, the corner of the quadrant is a quarter of the circle with the given radius
+        let (qx, qy) = (px.abs() - half_size.0 + radius, py.abs() - half_size.1 + radius);
+        edge_coverage(qx.max(qy).min(0.0) + qx.max(0.0).hypot(qy.max(0.0)) - radius, thickness)
+    });
+}
+
+#[derive(Clone, Copy)]
+struct Edge {
+    // edge of a shape going from the lower point x1, y1 to the upper point x2, y2
+    // winding is 1 if the edge was given upwards and -1 if it was given downwards
+    x1: f64,
+    y1: f64,
+    x2: f64,
+    y2: f64,
+    winding: i32,
+}
+
+#[derive(Clone)]
+pub(crate) struct Shape {
+    // shape made of closed polygons, fill rule decides which parts are inside of it
+    edges: Vec<Edge>,
+    fill_rule: FillRule,
+}
+
+impl Shape {
+    pub(crate) fn new(fill_rule: FillRule) -> Self {
+        Self { edges: Vec::new(), fill_rule }
+    }
+
+    pub(crate) fn add_polygon(&mut self, points: &[(f64, f64)]) {
+        // adds a closed polygon (the last point is connected to the first one), horizontal edges are skipped as they never cross a scanline
+        for (i, &(x1, y1)) in points.iter().enumerate() {
+            let (x2, y2) = points[(i + 1) % points.len()];
+            if y1 < y2 {
+                self.edges.push(Edge { x1, y1, x2, y2, winding: 1 });
+            } else if y1 > y2 {
+                self.edges.push(Edge { x1: x2, y1: y2, x2: x1, y2: y1, winding: -1 });
+            }
+        }
+    }
+
+    pub(crate) fn add_oriented_polygon(&mut self, points: &[(f64, f64)]) {
+        // adds a closed polygon oriented counterclockwise (so that the union of such polygons is filled with the non-zero rule)
+        let area: f64 = points.iter().enumerate().map(|(i, &(x1, y1))| {
+            let (x2, y2) = points[(i + 1) % points.len()];
+            x1 * y2 - x2 * y1
+        }).sum();
+        if area > 0.0 {
+            self.add_polygon(points);
+        } else if area < 0.0 {
+            let reversed: Vec<(f64, f64)> = points.iter().rev().copied().collect();
+            self.add_polygon(&reversed);
+        }
+    }
+
+    fn bounds(&self) -> Option<(f64, f64, f64, f64)> {
+        // returns the smallest x, smallest y, biggest x and biggest y of the shape
+        self.edges.iter().fold(None, |bounds, edge| {
+            let (min_x, min_y, max_x, max_y) = bounds.unwrap_or((f64::INFINITY, f64::INFINITY, f64::NEG_INFINITY, f64::NEG_INFINITY));
+            Some((min_x.min(edge.x1).min(edge.x2), min_y.min(edge.y1), max_x.max(edge.x1).max(edge.x2), max_y.max(edge.y2)))
+        })
+    }
+}
+
+pub(crate) enum Region {
+    // region of the plane built from shapes with boolean operations, which are evaluated exactly on every scanline
+    Shape(Shape),
+    Union(Box<Region>, Box<Region>),
+    Difference(Box<Region>, Box<Region>),
+}
+
+impl Region {
+    pub(crate) fn union(self, other: Region) -> Self {
+        Region::Union(Box::new(self), Box::new(other))
+    }
+
+    pub(crate) fn difference(self, other: Region) -> Self {
+        Region::Difference(Box::new(self), Box::new(other))
+    }
+
+    fn bounds(&self) -> Option<(f64, f64, f64, f64)> {
+        // returns the smallest x, smallest y, biggest x and biggest y of the region
+        match self {
+            Region::Shape(shape) => shape.bounds(),
+            Region::Union(a, b) => match (a.bounds(), b.bounds()) {
+                (Some(a), Some(b)) => Some((a.0.min(b.0), a.1.min(b.1), a.2.max(b.2), a.3.max(b.3))),
+                (a, b) => a.or(b),
+            },
+            Region::Difference(a, _) => a.bounds(),
+        }
+    }
+
+    fn scanner(&self) -> Scanner {
+        // returns the scanner that finds the spans of the region on horizontal lines going upwards
+        match self {
+            Region::Shape(shape) => {
+                let mut edges: Vec<Edge> = shape.edges.clone();
+                edges.sort_by(|first, second| first.y1.total_cmp(&second.y1));
+                Scanner::Shape { edges, fill_rule: shape.fill_rule, next: 0, active: Vec::new(), crossings: Vec::new(), spans: Vec::new() }
+            },
+            Region::Union(a, b) => Scanner::combined(a, b, |in_a, in_b| in_a || in_b),
+            Region::Difference(a, b) => Scanner::combined(a, b, |in_a, in_b| in_a && !in_b),
+        }
+    }
+}
+
+enum Scanner {
+    // state of the scanline rasterizer for a region, the horizontal lines have to be scanned from the bottom up,
+    // edges of every shape are sorted by their lower point, so only the edges crossing the current line (active edges) are checked,
+    // the buffers are reused between the lines
+    Shape {
+        edges: Vec<Edge>,
+        fill_rule: FillRule,
+        next: usize,
+        active: Vec<Edge>,
+        crossings: Vec<(f64, i32)>,
+        spans: Vec<(f64, f64)>,
+    },
+    Combined {
+        a: Box<Scanner>,
+        b: Box<Scanner>,
+        operation: fn(bool, bool) -> bool,
+        events: Vec<(f64, bool)>,
+        spans: Vec<(f64, f64)>,
+    },
+}
+
+impl Scanner {
+    fn combined(a: &Region, b: &Region, operation: fn(bool, bool) -> bool) -> Self {
+        Scanner::Combined { a: Box::new(a.scanner()), b: Box::new(b.scanner()), operation, events: Vec::new(), spans: Vec::new() }
+    }
+
+    fn spans(&mut self, y: f64) -> &[(f64, f64)] {
+        // returns the sorted spans of the horizontal line at y that lie inside of the region, y can't be smaller than in the previous call
+        match self {
+            Scanner::Shape { edges, fill_rule, next, active, crossings, spans } => {
+                while *next < edges.len() && edges[*next].y1 <= y {
+                    active.push(edges[*next]);
+                    *next += 1;
+                }
+                active.retain(|edge| y < edge.y2);
+
+                crossings.clear();
+                crossings.extend(active.iter().map(|edge| (edge.x1 + (y - edge.y1) * (edge.x2 - edge.x1) / (edge.y2 - edge.y1), edge.winding)));
+                crossings.sort_by(|first, second| first.0.total_cmp(&second.0));
+
+                let inside = |winding: i32| match fill_rule {
+                    FillRule::EvenOdd => winding % 2 != 0,
+                    FillRule::NonZero => winding != 0,
+                };
+                spans.clear();
+                let mut winding: i32 = 0;
+                let mut start: f64 = 0.0;
+                for &(x, edge_winding) in crossings.iter() {
+                    let inside_before: bool = inside(winding);
+                    winding += edge_winding;
+                    if !inside_before && inside(winding) {
+                        start = x;
+                    } else if inside_before && !inside(winding) {
+                        push_span(spans, start, x);
+                    }
+                }
+                spans
+            },
+            Scanner::Combined { a, b, operation, events, spans } => {
+                combine_spans(a.spans(y), b.spans(y), *operation, events, spans);
+                spans
+            },
+        }
+    }
+}
+
+fn add_stroke(shape: &mut Shape, points: &[(f64, f64)], closed: bool, half_width: f64, join: LineJoin, cap: LineCap) {
+    // adds the stroke with the given half width along the points (connecting the last point with the first one if closed) to the non-zero shape
+    // it is the union of a rectangle around every segment, a join at every joint and a cap at both ends (if not closed)
+
+    // duplicate points have no direction
+    let mut points: Vec<(f64, f64)> = points.to_vec();
+    points.dedup();
+    if closed && points.len() > 1 && points.first() == points.last() {
+        points.pop();
+    }
+    if half_width <= 0.0 || points.is_empty() {
+        return
+    }
+    let count: usize = points.len();
+    if count == 1 {
+        // single point only has caps (and only round caps, as it has no direction)
+        if cap == LineCap::Round && !closed {
+            shape.add_oriented_polygon(&circle_polygon(points[0], half_width));
+        }
+        return
+    }
+
+    let segments: usize = if closed { count } else { count - 1 };
+    for i in 0..segments {
+        let (x1, y1) = points[i];
+        let (x2, y2) = points[(i + 1) % count];
+        let (nx, ny) = unit_normal((x1, y1), (x2, y2));
+        let (nx, ny) = (nx * half_width, ny * half_width);
+        shape.add_oriented_polygon(&[(x1 + nx, y1 + ny), (x2 + nx, y2 + ny), (x2 - nx, y2 - ny), (x1 - nx, y1 - ny)]);
+    }
+
+    let joints = if closed { 0..count } else { 1..(count - 1) };
+    for i in joints {
+        let previous: (f64, f64) = points[(i + count - 1) % count];
+        let point: (f64, f64) = points[i];
+        let next: (f64, f64) = points[(i + 1) % count];
+        if join == LineJoin::Round {
+            shape.add_oriented_polygon(&circle_polygon(point, half_width));
+            continue
+        }
+        let normal1: (f64, f64) = unit_normal(previous, point);
+        let normal2: (f64, f64) = unit_normal(point, next);
+        // the join is on the outer side of the turn (right side if the path turns left)
+        let turn: f64 = (point.0 - previous.0) * (next.1 - point.1) - (point.1 - previous.1) * (next.0 - point.0);
+        let side: f64 = if turn > 0.0 { -half_width } else { half_width };
+        let a: (f64, f64) = (point.0 + normal1.0 * side, point.1 + normal1.1 * side);
+        let b: (f64, f64) = (point.0 + normal2.0 * side, point.1 + normal2.1 * side);
+        // miter is 1 / cos(angle / 2) = sqrt(2 / (1 + cos(angle))) half widths long, where angle is the angle between the normals
+        let cos: f64 = normal1.0 * normal2.0 + normal1.1 * normal2.1;
+        if join == LineJoin::Miter && (1.0 + cos) * MITER_LIMIT.powi(2) >= 2.0 {
+            let miter: (f64, f64) = (point.0 + (normal1.0 + normal2.0) * side / (1.0 + cos), point.1 + (normal1.1 + normal2.1) * side / (1.0 + cos));
+            shape.add_oriented_polygon(&[point, a, miter, b]);
+        } else {
+            shape.add_oriented_polygon(&[point, a, b]);
+        }
+    }
+
+    if !closed {
+        // caps at the first and the last point, both pointing away from the stroke
+        for (end, inner) in [(points[0], points[1]), (points[count - 1], points[count - 2])] {
+            match cap {
+                LineCap::Butt => (),
+                LineCap::Round => shape.add_oriented_polygon(&circle_polygon(end, half_width)),
+                LineCap::Square => {
+                    let (nx, ny) = unit_normal(inner, end);
+                    let (nx, ny) = (nx * half_width, ny * half_width);
+                    // direction away from the stroke is the normal rotated clockwise
+                    let (dx, dy) = (ny, -nx);
+                    shape.add_oriented_polygon(&[(end.0 + nx, end.1 + ny), (end.0 + nx + dx, end.1 + ny + dy), (end.0 - nx + dx, end.1 - ny + dy), (end.0 - nx, end.1 - ny)]);
+                },
+            }
+        }
+    }
+}
+
+fn add_line(shape: &mut Shape, start: (f64, f64), end: (f64, f64), half_width: f64) {
+    // adds the line with the given half width and butt ends to the non-zero shape
+    add_stroke(shape, &[start, end], false, half_width, LineJoin::Miter, LineCap::Butt);
+}
+
+fn fill(width: usize, height: usize, region: &Region, plot: &mut impl FnMut(usize, usize, f64)) {
+    // scanline rasterizer, every row of pixels is sampled with SUBSCANLINES horizontal lines,
+    // the spans of each line inside of the region are found exactly and their overlap with the pixels is accumulated
+
+    if let Some((min_x, min_y, max_x, max_y)) = region.bounds() {
+        if let (Some((lower_x, upper_x)), Some((lower_y, upper_y))) = (clip_range((min_x + 0.5).floor(), (max_x - 0.5).ceil(), width), clip_range((min_y + 0.5).floor(), (max_y - 0.5).ceil(), height)) {
+            let mut coverage: Vec<f64> = vec![0.0; upper_x - lower_x + 1];
+            let mut scanner: Scanner = region.scanner();
+            for y in lower_y..(upper_y + 1) {
+                // only the pixels between the first and the last touched one are plotted (and cleared for the next row)
+                let mut touched: Option<(usize, usize)> = None;
+                for subscanline in 0..SUBSCANLINES {
+                    let sample_y: f64 = y as f64 - 0.5 + (subscanline as f64 + 0.5) / SUBSCANLINES as f64;
+                    for &(start, end) in scanner.spans(sample_y) {
+                        if let Some((first_x, last_x)) = clip_range((start + 0.5).floor().max(lower_x as f64), (end - 0.5).ceil().min(upper_x as f64), width) {
+                            for x in first_x..(last_x + 1) {
+                                coverage[x - lower_x] += pixel_overlap(start, end, x) / SUBSCANLINES as f64;
+                            }
+                            touched = match touched {
+                                Some((first, last)) => Some((first.min(first_x), last.max(last_x))),
+                                None => Some((first_x, last_x)),
+                            };
+                        }
+                    }
+                }
+                if let Some((first_x, last_x)) = touched {
+                    for x in first_x..(last_x + 1) {
+                        let pixel_coverage: f64 = coverage[x - lower_x];
+                        if pixel_coverage > 0.0 {
+                            plot(x, y, pixel_coverage.min(1.0));
+                        }
+                        coverage[x - lower_x] = 0.0;
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn path_region(path: &Path, fill_rule: FillRule, thickness: usize) -> Region {
+    // region of the path with every subpath closed, filled if thickness is 0, otherwise thickness is added to the inside
+    // the pixels on the edges are fully covered, so the outer edge lies half a pixel outside of the path (union with a stroke 1 pixel wide),
+    // the inner edge lies thickness pixels inside of the outer edge (inside of the path minus a stroke 2 * thickness - 1 pixels wide)
+    let mut inside: Shape = Shape::new(fill_rule);
+    let mut outer_edge: Shape = Shape::new(FillRule::NonZero);
+    let mut inner_edge: Shape = Shape::new(FillRule::NonZero);
+    for subpath in &path.subpaths {
+        inside.add_polygon(&subpath.points);
+        add_stroke(&mut outer_edge, &subpath.points, true, 0.5, LineJoin::Miter, LineCap::Butt);
+        add_stroke(&mut inner_edge, &subpath.points, true, thickness as f64 - 0.5, LineJoin::Miter, LineCap::Butt);
+    }
+    let mut region: Region = Region::Shape(inside.clone()).union(Region::Shape(outer_edge));
+    if thickness != 0 {
+        region = region.difference(Region::Shape(inside).difference(Region::Shape(inner_edge)));
+    }
+    region
+}
+
+pub(crate) fn polygon(width: usize, height: usize, points: &[(f64, f64)], fill_rule: FillRule, thickness: usize, plot: &mut impl FnMut(usize, usize, f64)) {
+    // closed polygon through the points, filled if thickness is 0, otherwise thickness is added to the inside (the pixels on the edges are included)
+    fill(width, height, &path_region(&Path::polygon(points), fill_rule, thickness), plot);
+}
+
+pub(crate) fn fill_path(width: usize, height: usize, path: &Path, fill_rule: FillRule, plot: &mut impl FnMut(usize, usize, f64)) {
+    // exact inside of the path with every subpath closed (unlike the polygon, it isn't grown to include the pixels on the edges)
+    let mut shape: Shape = Shape::new(fill_rule);
+    for subpath in &path.subpaths {
+        shape.add_polygon(&subpath.points);
+    }
+    fill(width, height, &Region::Shape(shape), plot);
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn stroke_path(width: usize, height: usize, path: &Path, thickness: usize, join: LineJoin, cap: LineCap, plot: &mut impl FnMut(usize, usize, f64)) {
+    // lines and curves of the path, thickness is measured perpendicular to them and centered on them
+    // open subpaths end with caps, closed subpaths are joined at their first point,
+    // the whole stroke is one region, so every pixel is plotted only once (even where the path crosses itself)
+    let mut shape: Shape = Shape::new(FillRule::NonZero);
+    for subpath in &path.subpaths {
+        add_stroke(&mut shape, &subpath.points, subpath.closed, thickness as f64 / 2.0, join, cap);
+    }
+    fill(width, height, &Region::Shape(shape), plot);
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn arrow(width: usize, height: usize, x1: f64, y1: f64, x2: f64, y2: f64, head: ArrowHead, head_length: f64, head_angle: f64, double_ended: bool, thickness: usize, plot: &mut impl FnMut(usize, usize, f64)) {
+    // line from x1, y1 to x2, y2 with a head at x2, y2 (and at x1, y1 if double ended), thickness is measured perpendicular to the line and centered on it
+    // sides of the head are head_length long and go back from the tip at head_angle to the line, the whole arrow is one region
+
+    let length: f64 = (x2 - x1).hypot(y2 - y1);
+    if thickness == 0 || length == 0.0 {
+        return
+    }
+    let half_width: f64 = thickness as f64 / 2.0;
+    let direction: (f64, f64) = ((x2 - x1) / length, (y2 - y1) / length);
+    let head_length: f64 = head_length.max(0.0);
+    let (sin, cos) = head_angle.sin_cos();
+    // filled heads cover the end of the line, so the line stops at the back of the head
+    let inset: f64 = match head {
+        ArrowHead::Open => 0.0,
+        ArrowHead::Filled => head_length * cos,
+        ArrowHead::Barbed => head_length * cos / 2.0,
+    }.max(0.0);
+
+    let mut shape: Shape = Shape::new(FillRule::NonZero);
+    let mut tips: Vec<((f64, f64), (f64, f64))> = vec![((x2, y2), (-direction.0, -direction.1))];
+    if double_ended {
+        tips.push(((x1, y1), direction));
+    }
+    if head_length > 0.0 {
+        for (tip, back) in tips {
+            // sides of the head are the backwards direction rotated by head_angle both ways
+            let side1: (f64, f64) = (tip.0 + head_length * (back.0 * cos - back.1 * sin), tip.1 + head_length * (back.0 * sin + back.1 * cos));
+            let side2: (f64, f64) = (tip.0 + head_length * (back.0 * cos + back.1 * sin), tip.1 + head_length * (back.1 * cos - back.0 * sin));
+            match head {
+                ArrowHead::Open => add_stroke(&mut shape, &[side1, tip, side2], false, half_width, LineJoin::Miter, LineCap::Butt),
+                ArrowHead::Filled => shape.add_oriented_polygon(&[tip, side1, side2]),
+                ArrowHead::Barbed => shape.add_oriented_polygon(&[tip, side1, (tip.0 + back.0 * inset, tip.1 + back.1 * inset), side2]),
+            }
+        }
+    }
+
+    let start_inset: f64 = if double_ended && head_length > 0.0 { inset } else { 0.0 };
+    let end_inset: f64 = if head_length > 0.0 { inset } else { 0.0 };
+    if start_inset + end_inset < length {
+        let start: (f64, f64) = (x1 + direction.0 * start_inset, y1 + direction.1 * start_inset);
+        let end: (f64, f64) = (x2 - direction.0 * end_inset, y2 - direction.1 * end_inset);
+        add_line(&mut shape, start, end, half_width);
+    }
+    fill(width, height, &Region::Shape(shape), plot);
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn regular_polygon(width: usize, height: usize, x: f64, y: f64, radius: f64, sides: usize, rotation: f64, thickness: usize, plot: &mut impl FnMut(usize, usize, f64)) {
+    // polygon with sides of the same length and vertices on the circle with the given center and radius,
+    // without rotation the first vertex points up, filled if thickness is 0, otherwise thickness is added to the inside
+    if sides < 3 || radius <= 0.0 {
+        return
+    }
+    let points: Vec<(f64, f64)> = (0..sides).map(|i| {
+        let angle: f64 = PI / 2.0 + rotation + 2.0 * PI * i as f64 / sides as f64;
+        (x + radius * angle.cos(), y + radius * angle.sin())
+    }).collect();
+    polygon(width, height, &points, FillRule::NonZero, thickness, plot);
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn star(width: usize, height: usize, x: f64, y: f64, outer_radius: f64, inner_radius: f64, points: usize, thickness: usize, plot: &mut impl FnMut(usize, usize, f64)) {
+    // star with the tips on the circle with outer_radius and the vertices between them on the circle with inner_radius (both around the center),
+    // the first tip points up, filled if thickness is 0, otherwise thickness is added to the inside
+    if points < 2 || outer_radius <= 0.0 {
+        return
+    }
+    let vertices: Vec<(f64, f64)> = (0..(2 * points)).map(|i| {
+        let angle: f64 = PI / 2.0 + PI * i as f64 / points as f64;
+        let radius: f64 = if i % 2 == 0 { outer_radius } else { inner_radius.max(0.0) };
+        (x + radius * angle.cos(), y + radius * angle.sin())
+    }).collect();
+    polygon(width, height, &vertices, FillRule::NonZero, thickness, plot);
+}
+
+fn wedge_distance(x: f64, y: f64, start_angle: f64, sweep: f64) -> f64 {
+    // returns the signed distance of the point x, y (relative to the center) to the wedge between the directions start_angle and start_angle + sweep
+    // (negative inside of it), the wedge is the intersection of the sides of its two edges (or their union, if it is wider than a half turn)
+    let (start_sin, start_cos) = start_angle.sin_cos();
+    let (end_sin, end_cos) = (start_angle + sweep).sin_cos();
+    // inside of the wedge is on the left side of its first edge and on the right side of its second edge
+    let start_distance: f64 = x * start_sin - y * start_cos;
+    let end_distance: f64 = y * end_cos - x * end_sin;
+    if sweep <= PI {
+        start_distance.max(end_distance)
+    } else {
+        start_distance.min(end_distance)
+    }
+}
+
+fn ellipse_point(horizontal_axis: f64, vertical_axis: f64, angle: f64) -> (f64, f64) {
+    // returns the point on the ellipse (relative to its center) in the direction angle from the center
+    let t: f64 = (horizontal_axis * angle.sin()).atan2(vertical_axis * angle.cos());
+    (horizontal_axis * t.cos(), vertical_axis * t.sin())
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn arc(width: usize, height: usize, x: f64, y: f64, horizontal_axis: f64, vertical_axis: f64, start_angle: f64, end_angle: f64, thickness: usize, plot: &mut impl FnMut(usize, usize, f64)) {
+    // part of the outline of the ellipse from start_angle counterclockwise to end_angle, thickness is added to the inside of the ellipse
+    // it is the outline of the whole ellipse cut by the wedge between the two angles (so the ends of the arc point to the center),
+    // the coverage of the outline is multiplied with the coverage of the wedge (which isn't moved outwards, so the arc ends exactly at the angles)
+
+    let sweep: f64 = arc_sweep(start_angle, end_angle);
+    if thickness == 0 || sweep == 0.0 || horizontal_axis <= 0.0 || vertical_axis <= 0.0 {
+        return
+    }
+    if sweep >= 2.0 * PI {
+        return ellipse(width, height, x, y, horizontal_axis, vertical_axis, 0.0, thickness, plot)
+    }
+    plot_field(width, height, (x, y), (horizontal_axis, vertical_axis), plot, |dx, dy| {
+        edge_coverage(ellipse_distance(dx, dy, horizontal_axis, vertical_axis), thickness) * (0.5 - wedge_distance(dx, dy, start_angle, sweep)).clamp(0.0, 1.0)
+    });
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn chord(width: usize, height: usize, x: f64, y: f64, horizontal_axis: f64, vertical_axis: f64, start_angle: f64, end_angle: f64, thickness: usize, plot: &mut impl FnMut(usize, usize, f64)) {
+    // part of the ellipse from start_angle counterclockwise to end_angle closed by a straight line, filled if thickness is 0, otherwise thickness is added to the inside
+    // it is the intersection of the ellipse and the side of the line with the arc, so its distance is the bigger of the distances to them
+
+    let sweep: f64 = arc_sweep(start_angle, end_angle);
+    if sweep == 0.0 || horizontal_axis <= 0.0 || vertical_axis <= 0.0 {
+        return
+    }
+    let start: (f64, f64) = ellipse_point(horizontal_axis, vertical_axis, start_angle);
+    let end: (f64, f64) = ellipse_point(horizontal_axis, vertical_axis, start_angle + sweep);
+    let length: f64 = (end.0 - start.0).hypot(end.1 - start.1);
+    if sweep >= 2.0 * PI || length == 0.0 {
+        return ellipse(width, height, x, y, horizontal_axis, vertical_axis, 0.0, thickness, plot)
+    }
+    plot_field(width, height, (x, y), (horizontal_axis, vertical_axis), plot, |dx, dy| {
+        // arc is on the right side of the line from the start to the end
+        let line_distance: f64 = ((end.0 - start.0) * (dy - start.1) - (end.1 - start.1) * (dx - start.0)) / length;
+        edge_coverage(ellipse_distance(dx, dy, horizontal_axis, vertical_axis).max(line_distance), thickness)
+    });
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn pie(width: usize, height: usize, x: f64, y: f64, horizontal_axis: f64, vertical_axis: f64, start_angle: f64, end_angle: f64, thickness: usize, plot: &mut impl FnMut(usize, usize, f64)) {
+    // part of the ellipse from start_angle counterclockwise to end_angle closed by two lines through the center, filled if thickness is 0, otherwise thickness is added to the inside
+    // it is the intersection of the ellipse and the wedge between the two angles, so its distance is the bigger of the distances to them
+
+    let sweep: f64 = arc_sweep(start_angle, end_angle);
+    if sweep == 0.0 || horizontal_axis <= 0.0 || vertical_axis <= 0.0 {
+        return
+    }
+    if sweep >= 2.0 * PI {
+        return ellipse(width, height, x, y, horizontal_axis, vertical_axis, 0.0, thickness, plot)
+    }
+    plot_field(width, height, (x, y), (horizontal_axis, vertical_axis), plot, |dx, dy| {
+        edge_coverage(ellipse_distance(dx, dy, horizontal_axis, vertical_axis).max(wedge_distance(dx, dy, start_angle, sweep)), thickness)
+    });
+}
@@ -0,0 +1,44 @@
+//! A module that contains the types that describe how shapes are filled and stroked.
+
+
+/// Rule that decides which parts of a shape with overlapping or self-intersecting edges are inside of the shape.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FillRule {
+    /// A point is inside if a ray from it crosses the edges an odd number of times (overlapping parts become holes)
+    EvenOdd,
+    /// A point is inside if the edges wind around it (clockwise minus counterclockwise) a non-zero number of times
+    NonZero,
+}
+
+/// Shape of the joints between the segments of a stroke.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LineJoin {
+    /// Outer edges of the segments are extended until they meet (beveled if the tip is longer than 4 times the thickness)
+    Miter,
+    /// Joint is rounded with a circle with the diameter of the thickness
+    Round,
+    /// Outer corners of the segments are connected with a straight edge
+    Bevel,
+}
+
+/// Shape of the ends of an open stroke.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LineCap {
+    /// Stroke ends exactly at the end point
+    Butt,
+    /// Stroke ends with a half circle around the end point
+    Round,
+    /// Stroke is extended past the end point by half of the thickness
+    Square,
+}
+
+/// Shape of the head of an arrow.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ArrowHead {
+    /// Two lines going back from the tip
+    Open,
+    /// Filled triangle
+    Filled,
+    /// Filled triangle with the back cut in halfway to the tip
+    Barbed,
+}